@@ -0,0 +1,79 @@
+//  ASYNCS.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 08:45:34
+//  Last edited:
+//    14 Oct 2026, 08:45:34
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows that `async` functions can be versioned like any other.
+//
+
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+use versioning::versioning;
+
+
+/***** HELPER FUNCTIONS *****/
+/// Runs a future that never waits to completion.
+///
+/// # Arguments
+/// - `fut`: The [`Future`] to run.
+///
+/// # Returns
+/// The output of the future.
+fn block_on<F: Future>(fut: F) -> F::Output {
+    let mut fut = pin!(fut);
+    let mut ctx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(res) = fut.as_mut().poll(&mut ctx) {
+            return res;
+        }
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+#[versioning(v1_0_0, v2_0_0)]
+mod defs {
+    pub struct Client {
+        pub retries: u32,
+    }
+
+    impl Client {
+        /// Async functions that are the same in every version
+        pub async fn retries(&self) -> u32 { self.retries }
+
+        /// Async functions with a body that differs per version
+        #[version("v1_0_0")]
+        pub async fn fetch(&self) -> String { "v1".into() }
+        #[version("v2_0_0")]
+        pub async fn fetch(&self) -> String { format!("v2 (after {} retries)", self.retries().await) }
+    }
+
+    /// Free-standing ones work too
+    #[version(min("v2_0_0"))]
+    pub async fn ping() -> bool { true }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    let old = v1_0_0::Client { retries: 1 };
+    let new = v2_0_0::Client { retries: 3 };
+    assert_eq!(block_on(old.retries()), 1);
+    assert_eq!(block_on(old.fetch()), "v1");
+    assert_eq!(block_on(new.fetch()), "v2 (after 3 retries)");
+    assert!(block_on(v2_0_0::ping()));
+}
@@ -0,0 +1,54 @@
+//  ATTR_ORDER.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 08:52:18
+//  Last edited:
+//    14 Oct 2026, 08:52:18
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows the order in which attributes are emitted on generated items.
+//!   Inspect it with `cargo +nightly rustc --example attr_order --
+//!   -Zunpretty=expanded`.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+/// In v2, this generates (apart from the doc comments):
+/// ```text
+/// #[repr(u16)]
+/// #[derive(Clone, Copy, Debug)]
+/// #[derive(PartialEq)]
+/// #[allow(clippy::enum_variant_names)]
+/// #[doc(alias = "Opcode")]
+/// #[non_exhaustive]
+/// pub enum Instruction { ... }
+/// ```
+/// i.e., injected representations first, then the remaining attributes in source order (with `version_attr`s replaced by
+/// the attributes they inject), and finally those generated because of options.
+#[versioning(v1_0_0, v2_0_0, non_exhaustive_from = "v2_0_0", doc_alias_renames = true)]
+#[derive(Clone, Copy, Debug)]
+#[version_attr("v2_0_0", derive(PartialEq), repr(u16))]
+#[allow(clippy::enum_variant_names)]
+#[repr(u8)]
+#[version_rename("v2_0_0", Instruction)]
+pub enum Opcode {
+    OpNop,
+    OpJump,
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    assert_eq!(size_of::<v1_0_0::Opcode>(), 1);
+    assert_eq!(size_of::<v2_0_0::Instruction>(), 2);
+    assert_ne!(v2_0_0::Instruction::OpNop, v2_0_0::Instruction::OpJump);
+    println!("{:?} {:?} {:?}", v1_0_0::Opcode::OpNop, v1_0_0::Opcode::OpJump, v2_0_0::Instruction::OpJump);
+}
@@ -0,0 +1,54 @@
+//  BARE VERSIONS.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 09:33:44
+//  Last edited:
+//    14 Oct 2026, 09:34:08
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows that versions in `#[version(...)]` may be given as bare
+//!   identifiers, just like in `#[versioning(...)]`.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+#[versioning(v1_0_0, v2_0_0, v3_0_0)]
+mod defs {
+    pub struct Example {
+        #[version(v1_0_0)]
+        pub old:   bool,
+        /// Mixes freely with the quoted form
+        #[version(any(v1_0_0, "v3_0_0"))]
+        pub odd:   bool,
+        /// Works as the operand of operators too
+        #[version(min(v2_0_0))]
+        pub new:   bool,
+        /// Including prefixes
+        #[version(not(v3))]
+        pub early: bool,
+        #[version(since = v2_0_0, until = v3_0_0)]
+        pub mid:   bool,
+        /// And in nested filters
+        #[version(all(any(v1_0_0, v2_0_0), not(v1_0_0)))]
+        pub both:  bool,
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    let v1 = v1_0_0::Example { old: true, odd: true, early: true };
+    let v2 = v2_0_0::Example { new: true, early: true, mid: true, both: true };
+    let v3 = v3_0_0::Example { odd: true, new: true };
+    println!("{} {} {}", v1.old, v1.odd, v1.early);
+    println!("{} {} {} {}", v2.new, v2.early, v2.mid, v2.both);
+    println!("{} {}", v3.odd, v3.new);
+}
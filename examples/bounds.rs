@@ -0,0 +1,68 @@
+//  BOUNDS.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 09:06:56
+//  Last edited:
+//    14 Oct 2026, 09:07:37
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to add bounds to generics in some versions only, which
+//!   are merged with the existing where-clause.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+#[versioning(v1_0_0, v2_0_0)]
+mod defs {
+    /// From v2 onwards, the inner value must also be printable, so the where-clause becomes `where I: Clone + Debug`.
+    #[version_bound(min("v2_0_0"), "I: std::fmt::Debug")]
+    pub struct Wrapper<I>
+    where
+        I: Clone,
+    {
+        pub inner: I,
+    }
+
+    #[version_bound(min("v2_0_0"), "I: std::fmt::Debug")]
+    impl<I> Wrapper<I>
+    where
+        I: Clone,
+    {
+        pub fn get(&self) -> I { self.inner.clone() }
+    }
+
+    /// Bounds on parameters that aren't in the where-clause are added inline, so this is `Cloner<T>` in v1 and
+    /// `Cloner<T: Clone>` in v2.
+    #[version_bound("v2_0_0", "T: Clone")]
+    pub struct Cloner<T> {
+        pub value: T,
+    }
+}
+
+
+
+/// Doesn't implement `Debug`, so it can only be wrapped by v1.
+#[derive(Clone)]
+struct Opaque;
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    let old = v1_0_0::Wrapper { inner: Opaque };
+    let new = v2_0_0::Wrapper { inner: 42 };
+    let _: Opaque = old.get();
+    println!("{:?}", new.get());
+
+    // A `Mutex` isn't `Clone`, so it can only be held by v1
+    let old = v1_0_0::Cloner { value: std::sync::Mutex::new(0) };
+    let new = v2_0_0::Cloner { value: String::from("Hello, world!") };
+    println!("{:?} {}", old.value, new.value.clone());
+}
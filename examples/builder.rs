@@ -0,0 +1,47 @@
+//  BUILDER.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:41:07
+//  Last edited:
+//    14 Oct 2026, 08:27:50
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows the generation of per-version builders for structs.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+/// Every struct with named fields gets a `...Builder` that only knows the fields of its version
+#[versioning(v1_0_0, v2_0_0, builder = true)]
+mod defs {
+    pub struct Example<I> {
+        pub name:     String,
+        #[version("v1_0_0")]
+        pub data:     Vec<I>,
+        #[version("v2_0_0")]
+        pub contents: Vec<I>,
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    // Version 1 only has `name` and `data`...
+    let a: v1_0_0::Example<u64> = v1_0_0::ExampleBuilder::new().name("Hello, world!".into()).data(vec![1, 2, 3]).build().unwrap();
+    println!("{} {:?}", a.name, a.data);
+
+    // ...whereas version 2 replaced the latter with `contents`
+    let b: v2_0_0::Example<u64> = v2_0_0::ExampleBuilder::new().name("Hello, world!".into()).contents(vec![1, 2, 3]).build().unwrap();
+    println!("{} {:?}", b.name, b.contents);
+
+    // Unset fields are reported when building
+    assert!(v2_0_0::ExampleBuilder::<u64>::new().name("Hello, world!".into()).build().is_err());
+}
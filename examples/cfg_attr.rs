@@ -0,0 +1,72 @@
+//  CFG_ATTR.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 08:34:27
+//  Last edited:
+//    14 Oct 2026, 10:17:49
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how `version(...)` can be nested in `#[cfg_attr(...)]` to only
+//!   filter versions if some predicate holds.
+//
+
+
+/***** LIBRARY *****/
+mod defs {
+    use versioning::versioning;
+
+    /// Note that `all()` is always true and `any()` is always false; but any predicate works, e.g., `feature = "..."`
+    #[versioning(v1_0_0, v2_0_0)]
+    pub struct Example {
+        /// When the predicate holds, this field only exists in `v2_0_0`...
+        #[cfg_attr(all(), version("v2_0_0"))]
+        pub foo: String,
+        /// ...and when it doesn't, the field exists in all versions
+        #[cfg_attr(any(), version("v2_0_0"))]
+        pub bar: u64,
+        /// Other attributes in the `cfg_attr` are kept as-is
+        #[cfg_attr(all(), version("v1_0_0"), doc = "Only exists in `v1_0_0`.")]
+        pub baz: bool,
+    }
+}
+
+/// The generated helpers see the same fields as the struct itself
+mod helpers {
+    use versioning::versioning;
+
+    #[versioning(v1_0_0, v2_0_0, builder = true, default = true, conversions = true, field_matrix = true, field_names_const = true)]
+    #[derive(Debug)]
+    pub struct Config {
+        pub name: String,
+        #[cfg_attr(all(), version("v2_0_0"))]
+        pub port: u16,
+        #[cfg_attr(any(), version("v2_0_0"))]
+        pub host: String,
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    let a = defs::v1_0_0::Example { bar: 42, baz: true };
+    let b = defs::v2_0_0::Example { foo: "Hello, world!".into(), bar: 84 };
+    println!("{} {} | {} {}", a.bar, a.baz, b.foo, b.bar);
+
+    use helpers::{v1_0_0, v2_0_0};
+    let config: v1_0_0::Config = v1_0_0::ConfigBuilder::new().name("server".into()).host("localhost".into()).build().unwrap();
+    let config: v2_0_0::Config = config.into();
+    assert_eq!((config.name.as_str(), config.port, config.host.as_str()), ("server", 0, "localhost"));
+    let config: v1_0_0::Config = v2_0_0::Config { port: 8080, ..Default::default() }.into();
+    println!("{config:?}");
+    assert_eq!(v1_0_0::CONFIG_FIELDS, &["name", "host"]);
+    assert_eq!(v2_0_0::CONFIG_FIELDS, &["name", "port", "host"]);
+    assert_eq!(v1_0_0::CONFIG_FIELD_MATRIX, v2_0_0::CONFIG_FIELD_MATRIX);
+    assert_eq!(v1_0_0::CONFIG_FIELD_MATRIX[1], ("port", &["v2_0_0"][..]));
+    assert_eq!(v1_0_0::CONFIG_FIELD_MATRIX[2], ("host", &["v1_0_0", "v2_0_0"][..]));
+}
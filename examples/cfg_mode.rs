@@ -0,0 +1,64 @@
+//  CFG MODE.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 08:37:32
+//  Last edited:
+//    14 Oct 2026, 08:37:32
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to gate versions on a custom `cfg` instead of a cargo
+//!   feature with `cfg_mode`. Typically, this is something like
+//!   `cfg(schema_version = "{version}")` set with `--cfg`; here, the
+//!   predicate is negated so all versions are compiled without passing
+//!   any flags.
+//
+
+// The `schema_excluded` cfg is never set, so all versions are compiled
+#![allow(unexpected_cfgs)]
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+/// Every version is gated by `#[cfg(not(schema_excluded = "<version>"))]`.
+#[versioning(v1_0_0, v2_0_0, cfg_mode = "cfg(not(schema_excluded = \"{version}\"))")]
+mod defs {
+    pub struct Example {
+        pub name: String,
+        #[version("v2_0_0")]
+        pub tags: Vec<String>,
+    }
+}
+
+/// Which combines with `coalesce`, gating the merged versions on any of their predicates.
+mod coalesced {
+    use super::versioning;
+
+    #[versioning(v1_0_0, v1_0_1, v2_0_0, cfg_mode = "cfg(not(schema_excluded = \"{version}\"))", coalesce = true)]
+    pub mod defs {
+        pub struct Example {
+            pub name: String,
+            #[version("v2_0_0")]
+            pub tags: Vec<String>,
+        }
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    let a = v1_0_0::Example { name: "a".into() };
+    let b = v2_0_0::Example { name: "b".into(), tags: vec!["new".into()] };
+    println!("{} {} {:?}", a.name, b.name, b.tags);
+
+    // `v1_0_1` is an alias of `v1_0_0`, so these are the same type
+    let c: coalesced::v1_0_0::Example = coalesced::v1_0_1::Example { name: "c".into() };
+    let d = coalesced::v2_0_0::Example { name: "d".into(), tags: vec![] };
+    println!("{} {} {:?}", c.name, d.name, d.tags);
+}
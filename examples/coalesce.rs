@@ -0,0 +1,82 @@
+//  COALESCE.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 08:35:36
+//  Last edited:
+//    14 Oct 2026, 10:44:03
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to merge consecutive versions that generate identical
+//!   code, which saves on generated code for schemas with many versions
+//!   that don't change their structure.
+//
+
+// The version features are never defined in this crate
+#![allow(unexpected_cfgs)]
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+/// Only `v1_0_0` and `v2_0_0` are generated, and `v1_0_1` and `v1_0_2` are aliases of `v1_0_0`.
+#[versioning(v1_0_0, v1_0_1, v1_0_2, v2_0_0, coalesce = true)]
+mod defs {
+    pub struct Example {
+        pub name: String,
+        #[version("v2_0_0")]
+        pub tags: Vec<String>,
+    }
+}
+
+/// Works when the versioned item isn't a module too.
+mod toplevel {
+    use super::versioning;
+
+    #[versioning(v1_0_0, v2_0_0, v3_0_0, coalesce = true)]
+    pub struct Point {
+        pub x: i64,
+        #[version(max("v2_0_0"))]
+        pub y: i64,
+    }
+}
+
+/// With `features`, the merged module is enabled by any of the versions' features instead. This crate doesn't define them,
+/// so it's compiled out here; inspect it with `cargo expand --example coalesce`.
+mod gated {
+    use super::versioning;
+
+    #[versioning(v1_0_0, v1_0_1, v1_0_2, v2_0_0, features = true, coalesce = true)]
+    pub mod defs {
+        pub struct Example {
+            pub name: String,
+            #[version("v2_0_0")]
+            pub tags: Vec<String>,
+        }
+    }
+}
+
+
+
+/// Takes the generated version, which the aliases are the same type as.
+fn describe(example: &v1_0_0::Example) -> String { format!("Example '{}'", example.name) }
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    let a = v1_0_0::Example { name: "a".into() };
+    let b = v1_0_1::Example { name: "b".into() };
+    let c = v1_0_2::Example { name: "c".into() };
+    println!("{} {} {}", describe(&a), describe(&b), describe(&c));
+    let d = v2_0_0::Example { name: "d".into(), tags: vec!["new".into()] };
+    println!("{} {:?}", d.name, d.tags);
+
+    let p: toplevel::v2_0_0::Point = toplevel::v1_0_0::Point { x: 1, y: 2 };
+    let q = toplevel::v3_0_0::Point { x: 3 };
+    println!("{} {} {}", p.x, p.y, q.x);
+}
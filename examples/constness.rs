@@ -0,0 +1,47 @@
+//  CONSTNESS.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 08:46:14
+//  Last edited:
+//    14 Oct 2026, 08:46:14
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how functions can be `const` in some versions only.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+#[versioning(v1_0_0, v2_0_0)]
+mod defs {
+    pub struct Limits {
+        pub max: u32,
+    }
+
+    impl Limits {
+        /// The constructor was made `const` in v2
+        #[version_constness(min("v2_0_0"))]
+        pub fn new(max: u32) -> Self { Self { max } }
+    }
+
+    /// Functions declared `const` can also lose it in specific versions
+    #[version_constness(not("v1_0_0"))]
+    pub const fn double(value: u32) -> u32 { value * 2 }
+}
+
+/// Only v2's functions can be used in constants
+const LIMITS: v2_0_0::Limits = v2_0_0::Limits::new(v2_0_0::double(21));
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    let limits = v1_0_0::Limits::new(v1_0_0::double(21));
+    assert_eq!(limits.max, LIMITS.max);
+}
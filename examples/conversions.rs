@@ -0,0 +1,134 @@
+//  CONVERSIONS.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 09:15:19
+//  Last edited:
+//    14 Oct 2026, 09:18:12
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to generate `From`-conversions between the versions of a
+//!   struct, both between consecutive versions and between arbitrary
+//!   pairs of them, optionally computing fields from the old version.
+//!   Fields that change type are converted with `Into`.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+/// Converts between consecutive versions, and directly from the first to the last.
+///
+/// The last pair isn't directly compatible because `retries` changes type, so it is composed through `v2_0_0` (where it's
+/// dropped and then defaulted again).
+mod consecutive {
+    use super::versioning;
+
+    #[versioning(v1_0_0, v2_0_0, v3_0_0, conversions = true, conversion_pairs = "v1_0_0 -> v3_0_0", compose_conversions = true)]
+    pub mod defs {
+        #[derive(Debug)]
+        pub struct Config {
+            pub name:    String,
+            #[version(min("v2_0_0"))]
+            pub port:    u16,
+            #[version(any("v1_0_0", "v3_0_0"))]
+            #[version_type("v3_0_0", u64)]
+            pub retries: u32,
+        }
+    }
+}
+
+/// Converts between every ordered pair of versions.
+mod all {
+    use super::versioning;
+
+    #[versioning(v1_0_0, v2_0_0, v3_0_0, conversion_pairs = "all")]
+    #[derive(Debug)]
+    pub struct Point {
+        pub x:     i64,
+        pub y:     i64,
+        #[version(min("v2_0_0"))]
+        pub z:     i64,
+        #[version("v3_0_0")]
+        pub label: String,
+    }
+}
+
+
+
+
+
+/// Computes a field when upgrading instead of defaulting it.
+mod computed {
+    use super::versioning;
+
+    #[versioning(v1_0_0, v2_0_0, conversions = true)]
+    #[derive(Debug)]
+    pub struct Rect {
+        pub width:  u32,
+        pub height: u32,
+        #[version("v2_0_0")]
+        #[version_convert("v2_0_0", |old| old.width * old.height)]
+        pub area:   u32,
+    }
+}
+
+
+
+
+
+/// Converts fields that change type with `Into`, where the way back needs a closure as `u64` doesn't convert into `u32`.
+mod widened {
+    use super::versioning;
+
+    #[versioning(v1_0_0, v2_0_0, conversions = true)]
+    #[derive(Debug)]
+    pub struct Counter {
+        #[version_type("v2_0_0", u64)]
+        #[version_convert("v1_0_0", |old| u32::try_from(old.count).unwrap_or(u32::MAX))]
+        pub count: u32,
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    use consecutive::{v1_0_0, v2_0_0, v3_0_0};
+
+    // Upgrade step-by-step...
+    let old = v1_0_0::Config { name: "old".into(), retries: 3 };
+    let mid: v2_0_0::Config = old.into();
+    let new: v3_0_0::Config = mid.into();
+    println!("{} {} {}", new.name, new.port, new.retries);
+    // ...or downgrade again...
+    let mid: v2_0_0::Config = new.into();
+    let old: v1_0_0::Config = mid.into();
+    println!("{} {}", old.name, old.retries);
+    // ...or skip one
+    let new: v3_0_0::Config = old.into();
+    println!("{:?}", new);
+
+    // With `all`, any pair goes
+    let p1 = all::v1_0_0::Point { x: 1, y: 2 };
+    let p3: all::v3_0_0::Point = p1.into();
+    println!("{:?}", p3.label);
+    let p2: all::v2_0_0::Point = p3.into();
+    let p1: all::v1_0_0::Point = p2.into();
+    println!("{:?}", p1);
+
+    // Fields may be computed from the old version
+    let rect: computed::v2_0_0::Rect = computed::v1_0_0::Rect { width: 2, height: 3 }.into();
+    assert_eq!(rect.area, 6);
+    println!("{:?}", rect);
+
+    // Or converted if their type changed
+    let counter: widened::v2_0_0::Counter = widened::v1_0_0::Counter { count: 42 }.into();
+    assert_eq!(counter.count, 42u64);
+    let counter: widened::v1_0_0::Counter = widened::v2_0_0::Counter { count: u64::MAX }.into();
+    assert_eq!(counter.count, u32::MAX);
+}
@@ -0,0 +1,46 @@
+//  DEEP NESTING.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 09:43:21
+//  Last edited:
+//    14 Oct 2026, 09:43:21
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows that deeply nested modules are versioned too, which may
+//!   happen in machine-generated code.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+/// Nests 50 modules deep (counting `l01` itself), with a versioned struct at the bottom.
+#[versioning(v1_0_0, v2_0_0, nest_toplevel_modules = true)]
+#[rustfmt::skip]
+pub mod l01 {
+    pub mod l02 { pub mod l03 { pub mod l04 { pub mod l05 { pub mod l06 { pub mod l07 { pub mod l08 { pub mod l09 { pub mod l10 { pub mod l11 {
+    pub mod l12 { pub mod l13 { pub mod l14 { pub mod l15 { pub mod l16 { pub mod l17 { pub mod l18 { pub mod l19 { pub mod l20 { pub mod l21 {
+    pub mod l22 { pub mod l23 { pub mod l24 { pub mod l25 { pub mod l26 { pub mod l27 { pub mod l28 { pub mod l29 { pub mod l30 { pub mod l31 {
+    pub mod l32 { pub mod l33 { pub mod l34 { pub mod l35 { pub mod l36 { pub mod l37 { pub mod l38 { pub mod l39 { pub mod l40 { pub mod l41 {
+    pub mod l42 { pub mod l43 { pub mod l44 { pub mod l45 { pub mod l46 { pub mod l47 { pub mod l48 { pub mod l49 { pub mod l50 {
+        pub struct Leaf {
+            pub name: String,
+            #[version("v2_0_0")]
+            pub depth: usize,
+        }
+    }}}}}}}}}}}}}}}}}}}}}}}}}}}}}}}}}}}}}}}}}}}}}}}}}
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    let old = v1_0_0::l01::l02::l03::l04::l05::l06::l07::l08::l09::l10::l11::l12::l13::l14::l15::l16::l17::l18::l19::l20::l21::l22::l23::l24::l25::l26::l27::l28::l29::l30::l31::l32::l33::l34::l35::l36::l37::l38::l39::l40::l41::l42::l43::l44::l45::l46::l47::l48::l49::l50::Leaf { name: "old".into() };
+    let new = v2_0_0::l01::l02::l03::l04::l05::l06::l07::l08::l09::l10::l11::l12::l13::l14::l15::l16::l17::l18::l19::l20::l21::l22::l23::l24::l25::l26::l27::l28::l29::l30::l31::l32::l33::l34::l35::l36::l37::l38::l39::l40::l41::l42::l43::l44::l45::l46::l47::l48::l49::l50::Leaf { name: "new".into(), depth: 50 };
+    println!("{} {} {}", old.name, new.name, new.depth);
+}
@@ -0,0 +1,73 @@
+//  DEFAULT METHODS.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 09:38:21
+//  Last edited:
+//    14 Oct 2026, 09:38:21
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to version the provided (default) methods of a trait,
+//!   which keep their body in the versions they're in.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+#[versioning(v1_0_0, v2_0_0)]
+mod defs {
+    pub trait Greeter {
+        /// Required in every version
+        fn name(&self) -> String;
+
+        /// Only provided from v2 onwards
+        #[version("v2_0_0")]
+        fn greet(&self) -> String { format!("Hello, {}!", self.name()) }
+
+        /// Required methods may be versioned too
+        #[version("v2_0_0")]
+        fn farewell(&self) -> String;
+    }
+}
+
+
+
+/// Another trait with a `greet()`, which would make calling it ambiguous if v1's [`Greeter`](v1_0_0::Greeter) had one too.
+trait Fallback {
+    fn greet(&self) -> String;
+}
+impl<T: v1_0_0::Greeter> Fallback for T {
+    fn greet(&self) -> String { format!("Hi, {}.", self.name()) }
+}
+
+struct World;
+impl v1_0_0::Greeter for World {
+    fn name(&self) -> String { "world".into() }
+}
+impl v2_0_0::Greeter for World {
+    fn name(&self) -> String { "world".into() }
+
+    fn farewell(&self) -> String { format!("Goodbye, {}!", self.name()) }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    // v1 has no `greet()`, so this resolves to the fallback even though v1's trait is in scope
+    {
+        use v1_0_0::Greeter as _;
+        assert_eq!(World.name(), "world");
+        assert_eq!(World.greet(), "Hi, world.");
+    }
+
+    // v2 has, and then it uses its default body
+    assert_eq!(v2_0_0::Greeter::greet(&World), "Hello, world!");
+    assert_eq!(v2_0_0::Greeter::farewell(&World), "Goodbye, world!");
+    println!("{} {}", v2_0_0::Greeter::greet(&World), v2_0_0::Greeter::farewell(&World));
+}
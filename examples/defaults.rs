@@ -0,0 +1,58 @@
+//  DEFAULTS.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 11:02:48
+//  Last edited:
+//    14 Oct 2026, 08:28:49
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows the generation of version-specific [`Default`]-implementations.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+#[versioning(v1_0_0, v2_0_0, v3_0_0, default = true)]
+mod defs {
+    pub struct Example {
+        /// Fields without a default value use [`Default::default()`]...
+        pub name:    String,
+        /// ...whereas others can have a value per version (where the first match wins)
+        #[version_default_value("v1_0_0", 42)]
+        #[version_default_value(min("v2_0_0"), 84)]
+        pub retries: u32,
+        /// Only fields that exist in a particular version are defaulted
+        #[version(min("v3_0_0"))]
+        #[version_default_value("v3_0_0", 1.5)]
+        pub backoff: f64,
+    }
+
+    /// Works for unnamed structs too
+    pub struct Timeout(#[version_default_value(min("v2_0_0"), 30)] pub u64);
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    let a = v1_0_0::Example::default();
+    assert_eq!(a.name, "");
+    assert_eq!(a.retries, 42);
+    let b = v2_0_0::Example::default();
+    assert_eq!(b.name, "");
+    assert_eq!(b.retries, 84);
+    let c = v3_0_0::Example::default();
+    assert_eq!(c.name, "");
+    assert_eq!(c.retries, 84);
+    assert_eq!(c.backoff, 1.5);
+
+    assert_eq!(v1_0_0::Timeout::default().0, 0);
+    assert_eq!(v2_0_0::Timeout::default().0, 30);
+    assert_eq!(v3_0_0::Timeout::default().0, 30);
+}
@@ -0,0 +1,64 @@
+//  DERIVES.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 09:08:20
+//  Last edited:
+//    14 Oct 2026, 09:09:00
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to derive traits in some versions only. The injected
+//!   derives are merged into a single `#[derive(...)]`; inspect it with
+//!   `cargo +nightly rustc --example derives -- -Zunpretty=expanded`.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+#[versioning(v1_0_0, v2_0_0)]
+mod defs {
+    /// Is always `Clone`, but only `Debug` and `PartialEq` from v2 onwards.
+    #[derive(Clone)]
+    #[version_derive(min("v2_0_0"), Debug, PartialEq)]
+    pub struct Point {
+        pub x: i32,
+        pub y: i32,
+    }
+
+    /// Is `Copy` in v1, but not in v2 (as it gains a field that isn't).
+    #[derive(Clone, Copy, Debug)]
+    #[version_no_derive("v2_0_0", Copy)]
+    pub struct Label {
+        pub id:   u64,
+        #[version("v2_0_0")]
+        pub name: String,
+    }
+
+    /// Also works without any declared derives.
+    #[version_derive("v2_0_0", Clone, Copy, Debug)]
+    pub enum Axis {
+        X,
+        Y,
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    let old = v1_0_0::Point { x: 1, y: 2 };
+    let new = v2_0_0::Point { x: 1, y: 2 };
+    let copy = v2_0_0::Axis::X;
+    let _ = (old.clone().x, old.y, v1_0_0::Axis::X, v1_0_0::Axis::Y);
+    println!("{:?} {} {:?} {:?}", new.clone(), new == new.clone(), copy, v2_0_0::Axis::Y);
+
+    let old = v1_0_0::Label { id: 1 };
+    let copied = old;
+    let new = v2_0_0::Label { id: 2, name: "two".into() };
+    println!("{} {} {} {}", old.id, copied.id, new.clone().id, new.name);
+}
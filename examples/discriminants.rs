@@ -0,0 +1,87 @@
+//  DISCRIMINANTS.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 08:40:03
+//  Last edited:
+//    14 Oct 2026, 10:40:43
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how the discriminants of enum variants can differ per version.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+/// The numeric values of the message kinds were remapped in v2
+#[versioning(v1_0_0, v2_0_0)]
+#[derive(Clone, Copy)]
+pub enum Kind {
+    /// The declared discriminant is used unless a `#[version_discriminant(...)]` matches
+    #[version_discriminant("v2_0_0", 100)]
+    Request = 1,
+    #[version_discriminant("v2_0_0", 200)]
+    Response = 2,
+    /// Variants without a declared discriminant can be given one too
+    #[version_discriminant(min("v2_0_0"), 300)]
+    Error,
+}
+
+/// Discriminants may refer to constants that only exist in some versions, as long as the others override them
+mod codes {
+    use super::versioning;
+
+    #[versioning(v1_0_0, v2_0_0, pass_attrs(allow(dead_code)))]
+    pub mod defs {
+        #[version("v2_0_0")]
+        pub const RETRY: u8 = 42;
+        /// Only the bare name refers to the filtered out constant, so paths to other items of the same name are fine
+        pub mod legacy {
+            pub const RETRY: u8 = 7;
+        }
+
+        #[derive(Clone, Copy)]
+        #[repr(u8)]
+        pub enum Code {
+            Ok = 0,
+            #[version_discriminant("v1_0_0", 1)]
+            Retry = RETRY,
+            LegacyRetry = legacy::RETRY,
+        }
+
+        /// References to items with a surviving sibling of the same name are fine too
+        #[version("v1_0_0")]
+        pub const TIMEOUT: u8 = 10;
+        #[version(else)]
+        pub const TIMEOUT: u8 = 20;
+
+        #[derive(Clone, Copy)]
+        #[repr(u8)]
+        pub enum Limit {
+            Timeout = TIMEOUT,
+        }
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    assert_eq!(v1_0_0::Kind::Request as u32, 1);
+    assert_eq!(v1_0_0::Kind::Response as u32, 2);
+    assert_eq!(v1_0_0::Kind::Error as u32, 3);
+    assert_eq!(v2_0_0::Kind::Request as u32, 100);
+    assert_eq!(v2_0_0::Kind::Response as u32, 200);
+    assert_eq!(v2_0_0::Kind::Error as u32, 300);
+    assert_eq!(codes::v1_0_0::Code::Ok as u8, 0);
+    assert_eq!(codes::v1_0_0::Code::Retry as u8, 1);
+    assert_eq!(codes::v2_0_0::Code::Retry as u8, codes::v2_0_0::RETRY);
+    assert_eq!(codes::v1_0_0::Code::LegacyRetry as u8, 7);
+    assert_eq!(codes::v1_0_0::Limit::Timeout as u8, 10);
+    assert_eq!(codes::v2_0_0::Limit::Timeout as u8, 20);
+}
@@ -0,0 +1,46 @@
+//  DOCS.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 08:53:37
+//  Last edited:
+//    14 Oct 2026, 08:53:37
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how documentation can differ per version. Compare the
+//!   generated docs with `cargo doc --example docs`, or inspect them with
+//!   `cargo +nightly rustc --example docs -- -Zunpretty=expanded`.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+#[versioning(v1_0_0, v2_0_0)]
+mod defs {
+    /// Doc comments are kept in every version.
+    ///
+    #[version_doc("v1_0_0", " Connects over plain TCP.")]
+    #[version_doc(min("v2_0_0"), " Connects over TLS, falling back to plain TCP if the server does not support it.")]
+    pub struct Connection {
+        /// The address to connect to.
+        pub address:     String,
+        /// Fields can be documented differently per version too; this one is:
+        #[version_doc("v1_0_0", " Ignored.")]
+        #[version_doc("v2_0_0", " Only used when connecting over TLS.")]
+        pub certificate: Option<String>,
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    let old = v1_0_0::Connection { address: "localhost:1234".into(), certificate: None };
+    let new = v2_0_0::Connection { address: "localhost:1234".into(), certificate: Some("cert.pem".into()) };
+    println!("{} {:?} -> {} {:?}", old.address, old.certificate, new.address, new.certificate);
+}
@@ -0,0 +1,86 @@
+//  ELSE FILTERS.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 09:28:07
+//  Last edited:
+//    14 Oct 2026, 10:13:08
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to use `#[version(else)]` to define an item for all
+//!   versions in which its siblings with the same name don't exist.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+#[versioning(v1_0_0, v2_0_0, v3_0_0, conversions = true)]
+mod defs {
+    /// The first version had a special greeting...
+    #[version("v1_0_0")]
+    pub fn greet() -> &'static str { "Hello" }
+    /// ...which was later replaced
+    #[version(else)]
+    pub fn greet() -> &'static str { "Howdy" }
+
+    pub struct Server;
+    impl Server {
+        /// This also works for the items in `impl`s and `trait`s, and complements all siblings together
+        #[version("v1_0_0")]
+        pub fn port(&self) -> u16 { 80 }
+        #[version("v2_0_0")]
+        pub fn port(&self) -> u16 { 8080 }
+        #[version(else)]
+        pub fn port(&self) -> u16 { 443 }
+    }
+
+    /// Fields and variants can be complemented too, which is also what the generated conversions see
+    #[derive(Debug)]
+    pub struct Config {
+        pub name: String,
+        #[version("v1_0_0")]
+        #[version_convert("v1_0_0", |config| u16::try_from(config.port).unwrap_or(u16::MAX))]
+        pub port: u16,
+        #[version(else)]
+        pub port: u32,
+    }
+    #[derive(Debug, PartialEq)]
+    pub enum Status {
+        Ok,
+        #[version("v1_0_0")]
+        Failed(String),
+        #[version(else)]
+        Failed {
+            code:   u16,
+            reason: String,
+        },
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    assert_eq!(v1_0_0::greet(), "Hello");
+    assert_eq!(v2_0_0::greet(), "Howdy");
+    assert_eq!(v3_0_0::greet(), "Howdy");
+    assert_eq!(v1_0_0::Server.port(), 80);
+    assert_eq!(v2_0_0::Server.port(), 8080);
+    assert_eq!(v3_0_0::Server.port(), 443);
+
+    let config: v2_0_0::Config = v1_0_0::Config { name: "server".into(), port: 80 }.into();
+    assert_eq!(config.port, 80u32);
+    let config: v1_0_0::Config = v2_0_0::Config { name: config.name, port: 100_000 }.into();
+    assert_eq!((config.name.as_str(), config.port), ("server", u16::MAX));
+    let config: v3_0_0::Config = v2_0_0::Config { name: config.name, port: 8080 }.into();
+    let config: v2_0_0::Config = config.into();
+    assert_eq!(config.port, 8080);
+    assert_ne!(v1_0_0::Status::Failed("oops".into()), v1_0_0::Status::Ok);
+    assert_ne!(v2_0_0::Status::Failed { code: 500, reason: "oops".into() }, v2_0_0::Status::Ok);
+    assert_ne!(v3_0_0::Status::Failed { code: 500, reason: "oops".into() }, v3_0_0::Status::Ok);
+}
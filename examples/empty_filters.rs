@@ -0,0 +1,52 @@
+//  EMPTY FILTERS.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 09:01:00
+//  Last edited:
+//    14 Oct 2026, 09:35:32
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows what operators with empty or single-element lists of filters
+//!   match, which is mostly relevant when generating filters
+//!   programmatically.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+#[versioning(v1_0_0, v2_0_0)]
+mod defs {
+    pub struct Example {
+        /// An empty conjunction matches every version (as nothing has to hold)
+        #[version(all())]
+        pub all: bool,
+
+        /// An empty disjunction matches no version (as nothing holds)
+        #[version(any())]
+        pub any: bool,
+
+        /// With a single element, both are the same as that element on its own
+        #[version(all("v1_0_0"))]
+        pub all_one:    bool,
+        #[version(any("v2_0_0"))]
+        pub any_one:    bool,
+        #[version(any(all(not("v1_0_0"))))]
+        pub nested_one: bool,
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    let a = v1_0_0::Example { all: true, all_one: true };
+    let b = v2_0_0::Example { all: true, any_one: true, nested_one: true };
+    // Note that `any` does not exist in either
+    println!("{} {} | {} {} {}", a.all, a.all_one, b.all, b.any_one, b.nested_one);
+}
@@ -4,7 +4,7 @@
 //  Created:
 //    20 Dec 2023, 15:46:07
 //  Last edited:
-//    20 Dec 2023, 19:14:48
+//    14 Oct 2026, 09:18:53
 //  Auto updated?
 //    Yes
 //
@@ -24,6 +24,7 @@ enum Example {
     #[version("v1_0_0")]
     Variant1,
 
+    /// Note that the `u64` is at index 0 in `v3_0_0`, as the `String` before it is filtered out (which emits a warning)
     #[version(any("v2_0_0", "v3_0_0"))]
     Variant2(#[version("v2_0_0")] String, #[version("v3_0_0")] u64),
 
@@ -0,0 +1,43 @@
+//  EXTERN_CRATES.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 08:31:56
+//  Last edited:
+//    14 Oct 2026, 08:31:56
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how `extern crate`-renames can differ per version.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+#[versioning(v1_0_0, v2_0_0, v3_0_0)]
+mod defs {
+    /// The declared rename is used unless a `#[version_extern_rename(...)]` matches
+    #[version_extern_rename("v2_0_0", core_v2)]
+    pub extern crate core as core_v1;
+
+    /// Crates without a rename can be given one too
+    #[version_extern_rename(min("v3_0_0"), alloc_v3)]
+    pub extern crate alloc;
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    assert_eq!(v1_0_0::core_v1::cmp::max(1, 2), 2);
+    assert_eq!(v2_0_0::core_v2::cmp::max(1, 2), 2);
+    assert_eq!(v3_0_0::core_v1::cmp::max(1, 2), 2);
+
+    let _: v1_0_0::alloc::string::String = "Hello, world!".into();
+    let _: v2_0_0::alloc::string::String = "Hello, world!".into();
+    let _: v3_0_0::alloc_v3::string::String = "Hello, world!".into();
+}
@@ -0,0 +1,49 @@
+//  FIELD DIFF.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 09:17:11
+//  Last edited:
+//    14 Oct 2026, 09:17:11
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows the generation of constants that list which fields are added
+//!   and removed between consecutive versions.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+#[versioning(v1_0_0, v2_0_0, v3_0_0, field_diff = true)]
+mod defs {
+    pub struct FileConfig {
+        pub path:  String,
+        #[version(max("v2_0_0"))]
+        pub mode:  u32,
+        #[version(min("v2_0_0"))]
+        pub owner: String,
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    // The diff is the same in every version module, and shows the schema changes at a glance
+    assert_eq!(v1_0_0::FILE_CONFIG_FIELD_DIFF, v3_0_0::FILE_CONFIG_FIELD_DIFF);
+    assert_eq!(v2_0_0::FILE_CONFIG_FIELD_DIFF, &[("v1_0_0", "v2_0_0", &["owner"][..], &[][..]), ("v2_0_0", "v3_0_0", &[][..], &["mode"][..]),]);
+    for (from, to, added, removed) in v1_0_0::FILE_CONFIG_FIELD_DIFF {
+        println!("{from} -> {to}: +{added:?} -{removed:?}");
+    }
+
+    // Use the structs themselves too
+    let a = v1_0_0::FileConfig { path: "/".into(), mode: 0o755 };
+    let b = v2_0_0::FileConfig { path: "/".into(), mode: 0o755, owner: "root".into() };
+    let c = v3_0_0::FileConfig { path: "/".into(), owner: "root".into() };
+    println!("{} {} | {} {} {} | {} {}", a.path, a.mode, b.path, b.mode, b.owner, c.path, c.owner);
+}
@@ -0,0 +1,56 @@
+//  FIELD_MATRIX.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 08:29:35
+//  Last edited:
+//    14 Oct 2026, 08:29:35
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows the generation of constants that describe which fields exist
+//!   in which versions.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+#[versioning(v1_0_0, v2_0_0, v3_0_0, field_matrix = true)]
+mod defs {
+    pub struct FileConfig {
+        pub path:  String,
+        #[version(max("v2_0_0"))]
+        pub mode:  u32,
+        #[version(min("v2_0_0"))]
+        pub owner: String,
+    }
+
+    /// Unnamed fields are named after their index
+    pub struct Pair(pub u64, #[version("v3_0_0")] pub u64);
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    // The matrix is the same in every version module, and can be used to catch accidental field churn
+    assert_eq!(v1_0_0::FILE_CONFIG_FIELD_MATRIX, v3_0_0::FILE_CONFIG_FIELD_MATRIX);
+    assert_eq!(v2_0_0::FILE_CONFIG_FIELD_MATRIX, &[
+        ("path", &["v1_0_0", "v2_0_0", "v3_0_0"][..]),
+        ("mode", &["v1_0_0", "v2_0_0"][..]),
+        ("owner", &["v2_0_0", "v3_0_0"][..]),
+    ]);
+    assert_eq!(v1_0_0::PAIR_FIELD_MATRIX, &[("0", &["v1_0_0", "v2_0_0", "v3_0_0"][..]), ("1", &["v3_0_0"][..])]);
+    assert_eq!(v2_0_0::PAIR_FIELD_MATRIX, v3_0_0::PAIR_FIELD_MATRIX);
+
+    // Use the structs themselves too
+    let a = v1_0_0::FileConfig { path: "/".into(), mode: 0o755 };
+    let b = v2_0_0::FileConfig { path: "/".into(), mode: 0o755, owner: "root".into() };
+    let c = v3_0_0::FileConfig { path: "/".into(), owner: "root".into() };
+    println!("{} {} | {} {} {} | {} {}", a.path, a.mode, b.path, b.mode, b.owner, c.path, c.owner);
+    println!("{} | {} | {} {}", v1_0_0::Pair(1).0, v2_0_0::Pair(1).0, v3_0_0::Pair(1, 2).0, v3_0_0::Pair(1, 2).1);
+}
@@ -0,0 +1,50 @@
+//  FIELD NAMES.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 09:20:18
+//  Last edited:
+//    14 Oct 2026, 09:20:18
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows the generation of constants that list the names of the fields
+//!   of a struct in every version, e.g., to validate incoming keys.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+#[versioning(v1_0_0, v2_0_0, field_names_const = true)]
+mod defs {
+    pub struct Config {
+        pub name:  String,
+        #[version("v1_0_0")]
+        pub mode:  u32,
+        #[version("v2_0_0")]
+        pub owner: String,
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    // Only the fields of that version are listed
+    assert_eq!(v1_0_0::CONFIG_FIELDS, &["name", "mode"]);
+    assert_eq!(v2_0_0::CONFIG_FIELDS, &["name", "owner"]);
+
+    // Which makes it easy to check keys
+    let keys: [&str; 2] = ["name", "mode"];
+    assert!(keys.iter().all(|k| v1_0_0::CONFIG_FIELDS.contains(k)));
+    assert!(!keys.iter().all(|k| v2_0_0::CONFIG_FIELDS.contains(k)));
+
+    // Use the structs themselves too
+    let a = v1_0_0::Config { name: "a".into(), mode: 0o755 };
+    let b = v2_0_0::Config { name: "b".into(), owner: "root".into() };
+    println!("{} {} | {} {}", a.name, a.mode, b.name, b.owner);
+}
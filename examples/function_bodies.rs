@@ -0,0 +1,79 @@
+//  FUNCTION BODIES.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 09:46:11
+//  Last edited:
+//    14 Oct 2026, 09:46:11
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to use `#[versioning(...)]` on a single function, of
+//!   which the body is filtered per version.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+/// Generates `v1_0_0::checksum()`, `v2_0_0::checksum()` and `v3_0_0::checksum()`, each with the statements of that version.
+#[versioning(v1_0_0, v2_0_0, v3_0_0)]
+pub fn checksum(data: &[u8]) -> u32 {
+    let mut sum: u32 = 0;
+    for byte in data {
+        // Statements in nested blocks are filtered too
+        #[version("v1_0_0")]
+        {
+            sum = sum.wrapping_add(*byte as u32);
+        }
+        #[version(min("v2_0_0"))]
+        {
+            sum = sum.rotate_left(5) ^ (*byte as u32);
+        }
+    }
+
+    // Including `let`s and macros
+    #[version("v3_0_0")]
+    let sum: u32 = sum.wrapping_mul(31);
+    #[version("v3_0_0")]
+    debug_assert!(sum != u32::MAX);
+    sum
+}
+
+/// Works for the bodies of methods too.
+mod methods {
+    use super::versioning;
+
+    #[versioning(v1_0_0, v2_0_0)]
+    pub mod defs {
+        pub struct Counter(pub u32);
+        impl Counter {
+            pub fn step(&mut self) -> u32 {
+                self.0 += 1;
+                // Most expressions can't have attributes (yet), but blocks can
+                #[version("v2_0_0")]
+                {
+                    self.0 += 1;
+                }
+                self.0
+            }
+        }
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    assert_eq!(v1_0_0::checksum(b"ab"), 97 + 98);
+    assert_eq!(v2_0_0::checksum(b"ab"), (97u32.rotate_left(5)) ^ 98);
+    assert_eq!(v3_0_0::checksum(b"ab"), ((97u32.rotate_left(5)) ^ 98).wrapping_mul(31));
+
+    let mut old = methods::v1_0_0::Counter(0);
+    let mut new = methods::v2_0_0::Counter(0);
+    assert_eq!((old.step(), new.step()), (1, 2));
+    println!("{} {}", old.step(), new.step());
+}
@@ -0,0 +1,65 @@
+//  GATS.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 09:39:01
+//  Last edited:
+//    14 Oct 2026, 09:39:01
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to version generic associated types, including their
+//!   where-clauses.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+#[versioning(v1_0_0, v2_0_0)]
+mod defs {
+    pub trait Container {
+        /// Only lends out items from v2 onwards
+        #[version("v2_0_0")]
+        type Item<'a>
+        where
+            Self: 'a;
+
+        #[version("v2_0_0")]
+        fn first<'a>(&'a self) -> Option<Self::Item<'a>>;
+
+        fn len(&self) -> usize;
+    }
+
+    pub struct List(pub Vec<String>);
+    impl Container for List {
+        #[version("v2_0_0")]
+        type Item<'a>
+            = &'a str
+        where
+            Self: 'a;
+
+        #[version("v2_0_0")]
+        fn first<'a>(&'a self) -> Option<Self::Item<'a>> { self.0.first().map(String::as_str) }
+
+        fn len(&self) -> usize { self.0.len() }
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    use v1_0_0::Container as _;
+    use v2_0_0::Container as _;
+
+    let old = v1_0_0::List(vec!["a".into(), "b".into()]);
+    let new = v2_0_0::List(vec!["c".into(), "d".into()]);
+    assert_eq!(old.len(), 2);
+    assert_eq!(new.len(), 2);
+    assert_eq!(new.first(), Some("c"));
+    println!("{} {:?}", old.len(), new.first());
+}
@@ -0,0 +1,54 @@
+//  GEN_TESTS.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 08:47:37
+//  Last edited:
+//    14 Oct 2026, 10:49:27
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how basic tests can be generated for every version of a
+//!   struct. Run them with `cargo test --example gen_tests`.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+#[versioning(v1_0_0, v2_0_0, gen_tests = true, default = true, builder = true, conversions = true)]
+mod defs {
+    /// Generates `v1_0_0::request_versioning_tests` and `v2_0_0::request_versioning_tests`, and, as it can be compared,
+    /// `request_conversion_tests` to check that `path` survives converting to `v2_0_0` and back. These start from the default
+    /// value, so give fields a `#[version_default_value(...)]` that differs from their type's default to catch dropping them.
+    #[derive(PartialEq)]
+    pub struct Request {
+        #[version_default_value(min("v1_0_0"), "/index.html".into())]
+        pub path: String,
+        #[version("v2_0_0")]
+        pub body: Vec<u8>,
+    }
+
+    /// Generic structs are not tested (with a warning), as we wouldn't know what to instantiate them with
+    pub struct Wrapper<T: Default> {
+        pub value: T,
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    let old = v1_0_0::Request::default();
+    let new = v2_0_0::RequestBuilder::new().path("/".into()).body(vec![42]).build().unwrap();
+    println!("{:?} -> {:?} {:?}", old.path, new.path, new.body);
+    let old: v1_0_0::Request = new.into();
+    println!("{:?}", old.path);
+
+    let old = v1_0_0::WrapperBuilder::<u8>::new().value(1).build().unwrap();
+    let new = v2_0_0::WrapperBuilder::<u8>::new().value(2).build().unwrap();
+    println!("{} {}", old.value, new.value);
+}
@@ -4,7 +4,7 @@
 //  Created:
 //    20 Dec 2023, 16:10:34
 //  Last edited:
-//    21 Dec 2023, 10:47:16
+//    14 Oct 2026, 08:32:43
 //  Auto updated?
 //    Yes
 //
@@ -41,6 +41,33 @@ mod defs {
         #[version("v2_0_0")]
         pub fn bar(&self) -> u64 { self.bar }
     }
+
+    #[version("v1_0_0")]
+    pub trait Named {
+        fn name(&self) -> &'static str;
+    }
+    #[version("v2_0_0")]
+    pub trait Titled {
+        fn name(&self) -> &'static str;
+    }
+
+    /// The implemented trait can change per version...
+    #[version_impl_trait("v2_0_0", Titled)]
+    impl Named for Example1 {
+        fn name(&self) -> &'static str { "Example1" }
+    }
+
+    pub struct Example2;
+
+    /// ...or be removed altogether with `_`, making the impl inherent (and its methods private)
+    #[version_impl_trait("v2_0_0", _)]
+    impl Named for Example2 {
+        fn name(&self) -> &'static str { "Example2" }
+    }
+    #[version("v2_0_0")]
+    impl Example2 {
+        pub fn title(&self) -> &'static str { self.name() }
+    }
 }
 
 
@@ -56,6 +83,9 @@ fn main() {
         // This is version 1 space!
         let example: Example1 = Example1::new();
         println!("{}", example.foo());
+
+        use v1_0_0::Named as _;
+        println!("{} {}", example.name(), v1_0_0::Example2.name());
     }
     {
         use v2_0_0::Example1;
@@ -63,5 +93,8 @@ fn main() {
         // This is version 2 space!
         let example: Example1 = Example1::new();
         println!("{}", example.bar());
+
+        use v2_0_0::Titled as _;
+        println!("{} {}", example.name(), v2_0_0::Example2.title());
     }
 }
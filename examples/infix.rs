@@ -0,0 +1,54 @@
+//  INFIX.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 09:36:29
+//  Last edited:
+//    14 Oct 2026, 09:36:29
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to write filters with the infix operators `!`, `&&` and
+//!   `||`, grouped with parentheses.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+#[versioning(v1_0_0, v1_5_0, v2_0_0, v3_0_0, field_names_const = true, pass_attrs(allow(dead_code)))]
+mod defs {
+    pub struct Example {
+        /// Groups bind first...
+        #[version(("v1_0_0" || "v2_0_0") && !"v1_5_0")]
+        pub grouped:  bool,
+        /// ...then `!`, then `&&` and then `||`, so this is `any(all("v1_0_0", "v2_0_0"), "v3_0_0")`...
+        #[version("v1_0_0" && "v2_0_0" || "v3_0_0")]
+        pub and_or:   bool,
+        /// ...and this is `any("v1_0_0", all(not("v2_0_0"), "v3_0_0"))`
+        #[version("v1_0_0" || !"v2_0_0" && "v3_0_0")]
+        pub or_and:   bool,
+        /// Groups nest, and mix with the operator functions and bare versions
+        #[version(!(min(v2_0_0) && (v2_0_0 || (v3_0_0))))]
+        pub nested:   bool,
+        /// Negations stack
+        #[version(!!"v1_5_0")]
+        pub negated:  bool,
+        /// And whole expressions may be used as operands in turn
+        #[version(any("v1_0_0" && "v1_5_0", !("v1_0_0" || "v1_5_0")))]
+        pub operands: bool,
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    assert_eq!(v1_0_0::EXAMPLE_FIELDS, &["grouped", "or_and", "nested"]);
+    assert_eq!(v1_5_0::EXAMPLE_FIELDS, &["nested", "negated"]);
+    assert_eq!(v2_0_0::EXAMPLE_FIELDS, &["grouped", "operands"]);
+    assert_eq!(v3_0_0::EXAMPLE_FIELDS, &["and_or", "or_and", "operands"]);
+}
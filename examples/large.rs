@@ -0,0 +1,149 @@
+//  LARGE.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 09:00:07
+//  Last edited:
+//    14 Oct 2026, 10:14:38
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   A larger input with many versions and filtered fields, which checks
+//!   that the macro handles inputs beyond the toy examples.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+#[versioning(
+    v1_0_0,
+    v1_1_0,
+    v1_2_0,
+    v1_3_0,
+    v1_4_0,
+    v1_5_0,
+    v1_6_0,
+    v1_7_0,
+    v1_8_0,
+    v1_9_0,
+    v1_10_0,
+    v1_11_0,
+    v1_12_0,
+    v1_13_0,
+    v1_14_0,
+    v1_15_0,
+    pass_attrs(allow(dead_code))
+)]
+mod defs {
+    #[derive(Debug, Default)]
+    pub struct Large {
+        #[version(any(min("v1_0_0"), "v1_0_0"))]
+        pub field_0:  u8,
+        #[version(any(max("v1_1_0"), "v1_7_0"))]
+        pub field_1:  u16,
+        #[version(any(mne("v1_2_0"), "v1_14_0"))]
+        pub field_2:  u32,
+        #[version(any(mxe("v1_3_0"), "v1_5_0"))]
+        pub field_3:  u64,
+        #[version(any(min("v1_4_0"), "v1_12_0"))]
+        pub field_4:  u8,
+        #[version(any(max("v1_5_0"), "v1_3_0"))]
+        pub field_5:  u16,
+        #[version(any(mne("v1_6_0"), "v1_10_0"))]
+        pub field_6:  u32,
+        #[version(any(mxe("v1_7_0"), "v1_1_0"))]
+        pub field_7:  u64,
+        #[version(any(min("v1_8_0"), "v1_8_0"))]
+        pub field_8:  u8,
+        #[version(any(max("v1_9_0"), "v1_15_0"))]
+        pub field_9:  u16,
+        #[version(any(mne("v1_10_0"), "v1_6_0"))]
+        pub field_10: u32,
+        #[version(any(mxe("v1_11_0"), "v1_13_0"))]
+        pub field_11: u64,
+        #[version(any(min("v1_12_0"), "v1_4_0"))]
+        pub field_12: u8,
+        #[version(any(max("v1_13_0"), "v1_11_0"))]
+        pub field_13: u16,
+        #[version(any(mne("v1_14_0"), "v1_2_0"))]
+        pub field_14: u32,
+        #[version(any(mxe("v1_15_0"), "v1_9_0"))]
+        pub field_15: u64,
+        #[version(any(min("v1_0_0"), "v1_0_0"))]
+        pub field_16: u8,
+        #[version(any(max("v1_1_0"), "v1_7_0"))]
+        pub field_17: u16,
+        #[version(any(mne("v1_2_0"), "v1_14_0"))]
+        pub field_18: u32,
+        #[version(any(mxe("v1_3_0"), "v1_5_0"))]
+        pub field_19: u64,
+        #[version(any(min("v1_4_0"), "v1_12_0"))]
+        pub field_20: u8,
+        #[version(any(max("v1_5_0"), "v1_3_0"))]
+        pub field_21: u16,
+        #[version(any(mne("v1_6_0"), "v1_10_0"))]
+        pub field_22: u32,
+        #[version(any(mxe("v1_7_0"), "v1_1_0"))]
+        pub field_23: u64,
+        #[version(any(min("v1_8_0"), "v1_8_0"))]
+        pub field_24: u8,
+        #[version(any(max("v1_9_0"), "v1_15_0"))]
+        pub field_25: u16,
+        #[version(any(mne("v1_10_0"), "v1_6_0"))]
+        pub field_26: u32,
+        #[version(any(mxe("v1_11_0"), "v1_13_0"))]
+        pub field_27: u64,
+        #[version(any(min("v1_12_0"), "v1_4_0"))]
+        pub field_28: u8,
+        #[version(any(max("v1_13_0"), "v1_11_0"))]
+        pub field_29: u16,
+        #[version(any(mne("v1_14_0"), "v1_2_0"))]
+        pub field_30: u32,
+        #[version(any(mxe("v1_15_0"), "v1_9_0"))]
+        pub field_31: u64,
+        #[version(any(min("v1_0_0"), "v1_0_0"))]
+        pub field_32: u8,
+        #[version(any(max("v1_1_0"), "v1_7_0"))]
+        pub field_33: u16,
+        #[version(any(mne("v1_2_0"), "v1_14_0"))]
+        pub field_34: u32,
+        #[version(any(mxe("v1_3_0"), "v1_5_0"))]
+        pub field_35: u64,
+        #[version(any(min("v1_4_0"), "v1_12_0"))]
+        pub field_36: u8,
+        #[version(any(max("v1_5_0"), "v1_3_0"))]
+        pub field_37: u16,
+        #[version(any(mne("v1_6_0"), "v1_10_0"))]
+        pub field_38: u32,
+        #[version(any(mxe("v1_7_0"), "v1_1_0"))]
+        pub field_39: u64,
+        #[version(any(min("v1_8_0"), "v1_8_0"))]
+        pub field_40: u8,
+        #[version(any(max("v1_9_0"), "v1_15_0"))]
+        pub field_41: u16,
+        #[version(any(mne("v1_10_0"), "v1_6_0"))]
+        pub field_42: u32,
+        #[version(any(mxe("v1_11_0"), "v1_13_0"))]
+        pub field_43: u64,
+        #[version(any(min("v1_12_0"), "v1_4_0"))]
+        pub field_44: u8,
+        #[version(any(max("v1_13_0"), "v1_11_0"))]
+        pub field_45: u16,
+        #[version(any(mne("v1_14_0"), "v1_2_0"))]
+        pub field_46: u32,
+        #[version(any(mxe("v1_15_0"), "v1_9_0"))]
+        pub field_47: u64,
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    println!("{:?}", v1_0_0::Large::default());
+    println!("{:?}", v1_15_0::Large::default());
+}
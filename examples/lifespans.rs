@@ -0,0 +1,89 @@
+//  LIFESPANS.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 09:31:04
+//  Last edited:
+//    14 Oct 2026, 10:22:36
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to use the `since = ...` and `until = ...` shorthands (or
+//!   `added_in = ...` and `removed_in = ...`) to say when fields were
+//!   introduced and removed.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+#[versioning(v1_0_0, v2_0_0, v3_0_0, v4_0_0)]
+mod defs {
+    pub struct Example {
+        pub name:    String,
+        /// Introduced in `v2_0_0`, removed in `v4_0_0`
+        #[version(since = "v2_0_0", until = "v4_0_0")]
+        pub age:     u32,
+        /// Introduced in `v3_0_0`
+        #[version(since = "v3_0_0")]
+        pub email:   String,
+        /// Removed in `v2_0_0`
+        #[version(until = "v2_0_0")]
+        pub nick:    String,
+        /// Works as part of other filters too
+        #[version(any(until = "v2_0_0", since = "v4_0_0"))]
+        pub comment: String,
+    }
+}
+
+/// The deprecation-oriented spelling of the same, where the version a field is removed in is the first one without it.
+mod deprecated {
+    use super::versioning;
+
+    #[versioning(v1_0_0, v2_0_0, v3_0_0, v4_0_0, field_names_const = true, pass_attrs(allow(dead_code)))]
+    pub mod defs {
+        pub struct Settings {
+            /// Added in the first version, so it's in all of them
+            #[version(added_in = "v1_0_0")]
+            pub id:     u64,
+            #[version(added_in = "v2_0_0")]
+            pub theme:  String,
+            #[version(removed_in = "v3_0_0")]
+            pub colour: String,
+            #[version(added_in = "v2_0_0", removed_in = "v4_0_0")]
+            pub font:   String,
+            /// The bounds of a range can be given in either order...
+            #[version(removed_in = "v4_0_0", added_in = "v2_0_0")]
+            pub size:   u8,
+            /// ...but only form one at the toplevel, so this is any version before `v2_0_0` or from `v4_0_0`
+            #[version(any(added_in = "v4_0_0", removed_in = "v2_0_0"))]
+            pub legacy: bool,
+        }
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    let v1 = v1_0_0::Example { name: "v1".into(), nick: "one".into(), comment: "first".into() };
+    let v2 = v2_0_0::Example { name: "v2".into(), age: 2 };
+    let v3 = v3_0_0::Example { name: "v3".into(), age: 3, email: "v3@example.com".into() };
+    let v4 = v4_0_0::Example { name: "v4".into(), email: "v4@example.com".into(), comment: "last".into() };
+    println!("{} {} {}", v1.name, v1.nick, v1.comment);
+    println!("{} {}", v2.name, v2.age);
+    println!("{} {} {}", v3.name, v3.age, v3.email);
+    println!("{} {} {}", v4.name, v4.email, v4.comment);
+
+    // `removed_in` excludes exactly the version it names, and `added_in` includes it
+    assert_eq!(deprecated::v1_0_0::SETTINGS_FIELDS, &["id", "colour", "legacy"]);
+    assert_eq!(deprecated::v2_0_0::SETTINGS_FIELDS, &["id", "theme", "colour", "font", "size"]);
+    assert_eq!(deprecated::v3_0_0::SETTINGS_FIELDS, &["id", "theme", "font", "size"]);
+    assert_eq!(deprecated::v4_0_0::SETTINGS_FIELDS, &["id", "theme", "legacy"]);
+    let s2 = deprecated::v2_0_0::Settings { id: 2, theme: "dark".into(), colour: "red".into(), font: "mono".into(), size: 12 };
+    let s3 = deprecated::v3_0_0::Settings { id: 3, theme: "light".into(), font: "sans".into(), size: 14 };
+    println!("{} {} {} {} {} | {} {} {} {}", s2.id, s2.theme, s2.colour, s2.font, s2.size, s3.id, s3.theme, s3.font, s3.size);
+}
@@ -0,0 +1,50 @@
+//  MERGED CFGS.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 08:54:48
+//  Last edited:
+//    14 Oct 2026, 08:54:48
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows that an item's own `#[cfg(...)]` is merged with the version
+//!   gate. Inspect it with
+//!   `cargo +nightly rustc --example merged_cfgs -- -Zunpretty=expanded`,
+//!   which shows a single `#[cfg(all(...))]` on every version module.
+//
+
+// The `schema_excluded` cfg is never set, so all versions are compiled
+#![allow(unexpected_cfgs)]
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+mod linux {
+    use super::*;
+
+    #[versioning(v1_0_0, v2_0_0, cfg_mode = "cfg(not(schema_excluded = \"{version}\"))")]
+    #[cfg(target_os = "linux")]
+    pub struct Epoll {
+        pub fd:             i32,
+        #[version(min("v2_0_0"))]
+        pub edge_triggered: bool,
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+#[cfg(target_os = "linux")]
+fn main() {
+    let old = linux::v1_0_0::Epoll { fd: 3 };
+    let new = linux::v2_0_0::Epoll { fd: 3, edge_triggered: true };
+    println!("{} -> {} {}", old.fd, new.fd, new.edge_triggered);
+}
+
+#[cfg(not(target_os = "linux"))]
+fn main() {}
@@ -0,0 +1,51 @@
+//  MODULE NAMES.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 09:10:49
+//  Last edited:
+//    14 Oct 2026, 09:10:49
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to override the names of the generated version modules.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+mod wrapped {
+    use super::*;
+
+    // The version identifiers are still used in filters, but the modules are named differently
+    #[versioning(v1_0_0, v2_0_0, v3_0_0)]
+    #[version_module_name("v1_0_0", legacy)]
+    #[version_module_name("v3_0_0", latest)]
+    pub struct Config {
+        pub name: String,
+        #[version(min("v2_0_0"))]
+        pub port: u16,
+    }
+}
+
+// Also works for modules
+#[versioning(v1_0_0, v2_0_0)]
+#[version_module_name("v2_0_0", current)]
+pub mod defs {
+    pub struct Thing;
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    let a = wrapped::legacy::Config { name: "a".into() };
+    let b = wrapped::v2_0_0::Config { name: "b".into(), port: 2 };
+    let c = wrapped::latest::Config { name: "c".into(), port: 3 };
+    println!("{} {}:{} {}:{}", a.name, b.name, b.port, c.name, c.port);
+    let _ = (v1_0_0::Thing, current::Thing);
+}
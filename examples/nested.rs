@@ -0,0 +1,54 @@
+//  NESTED.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 08:56:46
+//  Last edited:
+//    14 Oct 2026, 08:56:46
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows that `#[versioning(...)]` can be nested, in which case every
+//!   outer version contains every inner version.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+#[versioning(v1_0_0, v2_0_0)]
+pub mod api {
+    use versioning::versioning;
+
+    // The `#[version(...)]` before the nested `#[versioning(...)]` is for the outer versions, the ones in it for the inner versions
+    #[version(min("v1_0_0"))]
+    #[versioning(wire1, wire2)]
+    pub mod wire {
+        pub struct Message {
+            pub id:       u64,
+            #[version("wire2")]
+            pub checksum: u32,
+        }
+    }
+
+    pub struct Client {
+        pub host: String,
+        #[version("v2_0_0")]
+        pub port: u16,
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    // Paths are of the shape `<outer version>::<inner version>::...`
+    let msg1 = v1_0_0::wire1::Message { id: 1 };
+    let msg2 = v2_0_0::wire2::Message { id: 2, checksum: 42 };
+    let client = v2_0_0::Client { host: "localhost".into(), port: 1234 };
+    let old = v1_0_0::Client { host: "localhost".into() };
+    println!("{} {} {} {}:{} {}", msg1.id, msg2.id, msg2.checksum, client.host, client.port, old.host);
+}
@@ -0,0 +1,62 @@
+//  NON_EXHAUSTIVE.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 08:26:44
+//  Last edited:
+//    14 Oct 2026, 08:26:44
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to mark the latest, still-evolving version(s) as
+//!   `#[non_exhaustive]`.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+/// Everything from `v2_0_0` onwards is marked as `#[non_exhaustive]`
+#[versioning(v1_0_0, v2_0_0, v3_0_0, non_exhaustive_from = "v2_0_0")]
+mod defs {
+    pub struct Example {
+        pub name: String,
+        #[version(min("v2_0_0"))]
+        pub id:   u64,
+    }
+
+    pub enum Kind {
+        Foo,
+        #[version(min("v3_0_0"))]
+        Bar,
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    // The frozen version can be destructured exhaustively...
+    let v1_0_0::Example { name } = v1_0_0::Example { name: "Hello, world!".into() };
+    match v1_0_0::Kind::Foo {
+        v1_0_0::Kind::Foo => println!("{name}"),
+    }
+
+    // ...while the newer ones would need a wildcard if used outside of this crate
+    let example = v2_0_0::Example { name: "Hello, world!".into(), id: 42 };
+    println!("{} {}", example.name, example.id);
+    match v2_0_0::Kind::Foo {
+        v2_0_0::Kind::Foo => println!("Foo"),
+    }
+    for kind in [v3_0_0::Kind::Foo, v3_0_0::Kind::Bar] {
+        match kind {
+            v3_0_0::Kind::Foo => println!("Foo"),
+            v3_0_0::Kind::Bar => println!("Bar"),
+        }
+    }
+    let example = v3_0_0::Example { name: "Hello, world!".into(), id: 42 };
+    println!("{} {}", example.name, example.id);
+}
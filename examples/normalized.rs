@@ -0,0 +1,47 @@
+//  NORMALIZED.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 08:38:18
+//  Last edited:
+//    14 Oct 2026, 08:38:18
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how inconsistently spelled versions can still generate
+//!   `snake_case` modules.
+//
+
+
+/***** LIBRARY *****/
+mod wrapped {
+    use versioning::versioning;
+
+    /// Filters still use the spelling from the version list, but the modules are all lowercase
+    #[versioning(V1_0_0, v2_0_0, normalize_module_case = true)]
+    pub struct Config {
+        #[version("V1_0_0")]
+        pub path:  String,
+        #[version("v2_0_0")]
+        pub paths: Vec<String>,
+    }
+}
+
+/// This also works when toplevel modules are renamed instead of wrapped
+#[versioning::versioning(V1_0_0, V2_0_0, normalize_module_case = true)]
+mod defs {
+    pub fn name() -> &'static str { "defs" }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    let old = wrapped::v1_0_0::Config { path: "/etc".into() };
+    let new = wrapped::v2_0_0::Config { paths: vec!["/etc".into(), "/usr/etc".into()] };
+    println!("{} -> {:?}", old.path, new.paths);
+    println!("{} & {}", v1_0_0::name(), v2_0_0::name());
+}
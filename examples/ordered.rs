@@ -4,7 +4,7 @@
 //  Created:
 //    20 Dec 2023, 16:23:38
 //  Last edited:
-//    21 Dec 2023, 10:47:23
+//    14 Oct 2026, 09:37:35
 //  Auto updated?
 //    Yes
 //
@@ -16,8 +16,8 @@ use versioning::versioning;
 
 
 /***** LIBRARY *****/
-/// The order of versions is determined by this order
-#[versioning(v1_0_0, v1_0_1, v1_1_0, v2_0_0)]
+/// The order of versions is determined by this order (and `assert_sorted` checks that it's the semantic one)
+#[versioning(v1_0_0, v1_0_1, v1_1_0, v2_0_0, assert_sorted = "semver")]
 mod defs {
     pub struct Example {
         /// This field is for the lower half...
@@ -32,6 +32,13 @@ mod defs {
         pub baz: String,
         #[version(mne("v1_0_1"))]
         pub quz: u64,
+
+        /// Prefixes are resolved to the one version they're a prefix of (and error if there are multiple, like for `v1`)...
+        #[version(mxe("v2"))]
+        pub old: bool,
+        /// ...which means they can be mixed with plain prefixes
+        #[version(all("v1", min("v1_1")))]
+        pub mid: bool,
     }
 }
 
@@ -42,8 +49,13 @@ mod defs {
 /***** ENTRYPOINT *****/
 fn main() {
     // This is how it works now
-    let _a = v1_0_0::Example { foo: "Hello, world!".into(), baz: "Goodbye, world!".into() };
-    let _b = v1_0_1::Example { foo: "Hello, world!".into(), baz: "Goodbye, world!".into() };
-    let _c = v1_1_0::Example { bar: 42, quz: 84 };
-    let _d = v2_0_0::Example { bar: 42, quz: 84 };
+    let a = v1_0_0::Example { foo: "Hello, world!".into(), baz: "Goodbye, world!".into(), old: true };
+    let b = v1_0_1::Example { foo: "Hello, world!".into(), baz: "Goodbye, world!".into(), old: true };
+    let c = v1_1_0::Example { bar: 42, quz: 84, old: true, mid: true };
+    let d = v2_0_0::Example { bar: 42, quz: 84 };
+    println!("{} {}", a.foo, a.baz);
+    println!("{} {}", b.foo, b.baz);
+    println!("{} {}", c.bar, c.quz);
+    println!("{} {} {} {}", a.old, b.old, c.old, c.mid);
+    println!("{} {}", d.bar, d.quz);
 }
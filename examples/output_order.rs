@@ -0,0 +1,136 @@
+//  OUTPUT_ORDER.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 08:49:02
+//  Last edited:
+//    14 Oct 2026, 08:49:22
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows (and asserts) the order in which version modules are emitted.
+//!
+//!   The order is observed by defining a macro in every version module,
+//!   and then exporting them with `#[macro_use]`. As later definitions
+//!   shadow earlier ones, the last emitted module wins (and the others
+//!   are never used).
+//
+
+
+/***** LIBRARY *****/
+mod declaration {
+    use versioning::versioning;
+
+    /// By default, modules are emitted in declaration order, which need not be sorted
+    #[versioning(v1_10_0, v1_9_0, v2_0_0, v1_0_0, pass_attrs(macro_use, allow(unused_macros)))]
+    mod defs {
+        #[version("v1_10_0")]
+        macro_rules! last {
+            () => {
+                "v1_10_0"
+            };
+        }
+        #[version("v1_9_0")]
+        macro_rules! last {
+            () => {
+                "v1_9_0"
+            };
+        }
+        #[version("v2_0_0")]
+        macro_rules! last {
+            () => {
+                "v2_0_0"
+            };
+        }
+        #[version("v1_0_0")]
+        macro_rules! last {
+            () => {
+                "v1_0_0"
+            };
+        }
+    }
+
+    pub fn last() -> &'static str { last!() }
+}
+
+mod semver {
+    use versioning::versioning;
+
+    /// Alternatively, they can be sorted by the numbers in the version
+    #[versioning(v1_10_0, v2_0_0, v1_9_0, v1_0_0, output_order = "semver", pass_attrs(macro_use, allow(unused_macros)))]
+    mod defs {
+        #[version("v1_10_0")]
+        macro_rules! last {
+            () => {
+                "v1_10_0"
+            };
+        }
+        #[version("v2_0_0")]
+        macro_rules! last {
+            () => {
+                "v2_0_0"
+            };
+        }
+        #[version("v1_9_0")]
+        macro_rules! last {
+            () => {
+                "v1_9_0"
+            };
+        }
+        #[version("v1_0_0")]
+        macro_rules! last {
+            () => {
+                "v1_0_0"
+            };
+        }
+    }
+
+    pub fn last() -> &'static str { last!() }
+}
+
+mod reverse {
+    use versioning::versioning;
+
+    /// They can also be emitted newest first (i.e., in reverse declaration order)
+    #[versioning(v1_0_0, v1_9_0, v1_10_0, v2_0_0, output_order = "reverse", pass_attrs(macro_use, allow(unused_macros)))]
+    mod defs {
+        #[version("v1_10_0")]
+        macro_rules! last {
+            () => {
+                "v1_10_0"
+            };
+        }
+        #[version("v2_0_0")]
+        macro_rules! last {
+            () => {
+                "v2_0_0"
+            };
+        }
+        #[version("v1_9_0")]
+        macro_rules! last {
+            () => {
+                "v1_9_0"
+            };
+        }
+        #[version("v1_0_0")]
+        macro_rules! last {
+            () => {
+                "v1_0_0"
+            };
+        }
+    }
+
+    pub fn last() -> &'static str { last!() }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    assert_eq!(declaration::last(), "v1_0_0");
+    assert_eq!(semver::last(), "v2_0_0");
+    assert_eq!(reverse::last(), "v1_0_0");
+}
@@ -4,7 +4,7 @@
 //  Created:
 //    20 Dec 2023, 16:45:55
 //  Last edited:
-//    21 Dec 2023, 10:47:29
+//    14 Oct 2026, 10:33:49
 //  Auto updated?
 //    Yes
 //
@@ -20,13 +20,21 @@ use versioning::versioning;
 #[versioning(v1_0_0, v1_0_1, v1_1_0, v2_0_0)]
 mod defs {
     pub struct Example {
-        /// Note that the string is actually matched as a prefix to the version
+        /// Note that the string is actually matched as a prefix to the version (but only whole segments, so not `v10_0_0`)
         #[version("v1")]
         pub foo: String,
 
         // So this is another way of matching *everything*
         #[version("")]
         pub bar: u64,
+
+        // Although it's clearer to say so explicitly
+        #[version(always)]
+        pub baz: bool,
+
+        // Which can also be done the other way around, e.g., to temporarily disable a field
+        #[version(never)]
+        pub quux: char,
     }
 }
 
@@ -37,8 +45,12 @@ mod defs {
 /***** ENTRYPOINT *****/
 fn main() {
     // This is how it works now
-    let _a = v1_0_0::Example { foo: "Hello, world!".into(), bar: 42 };
-    let _b = v1_0_1::Example { foo: "Hello, world!".into(), bar: 42 };
-    let _c = v1_1_0::Example { foo: "Hello, world!".into(), bar: 42 };
-    let _d = v2_0_0::Example { bar: 42 };
+    let a = v1_0_0::Example { foo: "Hello, world!".into(), bar: 42, baz: true };
+    let b = v1_0_1::Example { foo: "Hello, world!".into(), bar: 42, baz: true };
+    let c = v1_1_0::Example { foo: "Hello, world!".into(), bar: 42, baz: true };
+    let d = v2_0_0::Example { bar: 42, baz: true };
+    println!("{} {} {}", a.foo, a.bar, a.baz);
+    println!("{} {} {}", b.foo, b.bar, b.baz);
+    println!("{} {} {}", c.foo, c.bar, c.baz);
+    println!("{} {}", d.bar, d.baz);
 }
@@ -0,0 +1,44 @@
+//  PASS_ATTRS.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 08:33:43
+//  Last edited:
+//    14 Oct 2026, 08:33:43
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how attributes can be applied to all generated version modules
+//!   at once.
+//
+
+
+/***** LIBRARY *****/
+mod nested {
+    use versioning::versioning;
+
+    /// Attributes in `pass_attrs(...)` are applied to every generated version module, instead of to the wrapped item
+    #[versioning(v1_0_0, v2_0_0, pass_attrs(allow(dead_code), doc = "Never-used definitions."))]
+    pub struct Unused {
+        #[version("v2_0_0")]
+        pub foo: String,
+    }
+}
+
+mod renamed {
+    use versioning::versioning;
+
+    /// This also works when toplevel modules are renamed instead of wrapped
+    #[versioning(v1_0_0, v2_0_0, pass_attrs(allow(dead_code)))]
+    mod defs {
+        pub fn unused() {}
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {}
@@ -0,0 +1,44 @@
+//  RANGES.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 08:50:15
+//  Last edited:
+//    14 Oct 2026, 08:50:15
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how ranges of versions can be declared at once.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+/// Ranges count up the last segment of the version, and can be mixed with normal versions; this generates `v1_0_0`, `v1_0_1`,
+/// `v1_0_2`, `v1_1_0`, `v1_1_1` and `v2_0_0`.
+#[versioning(v1_0_0..=v1_0_2, v1_1_0..v1_1_2, v2_0_0)]
+mod defs {
+    pub struct Example {
+        #[version(max("v1_0_2"))]
+        pub name:  String,
+        #[version(min("v1_1_0"))]
+        pub names: Vec<String>,
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    let names = [v1_0_0::Example { name: "a".into() }.name, v1_0_1::Example { name: "b".into() }.name, v1_0_2::Example { name: "c".into() }.name];
+    let lists = [
+        v1_1_0::Example { names: vec!["d".into()] }.names,
+        v1_1_1::Example { names: vec!["e".into()] }.names,
+        v2_0_0::Example { names: vec!["f".into()] }.names,
+    ];
+    println!("{names:?} {lists:?}");
+}
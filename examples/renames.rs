@@ -0,0 +1,51 @@
+//  RENAMES.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 08:39:33
+//  Last edited:
+//    14 Oct 2026, 10:43:16
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how items can be renamed in specific versions.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+#[versioning(v1_0_0, v2_0_0, doc_alias_renames = true)]
+mod defs {
+    /// The configuration was renamed to `Settings` in v2, but is still found when searching the docs for `Config`
+    #[version_rename(min("v2_0_0"), Settings)]
+    pub struct Config {
+        pub verbose: bool,
+    }
+
+    /// Other items can be renamed too
+    #[version_rename("v2_0_0", Level)]
+    pub type Verbosity = u8;
+
+    /// ...including values like constants and functions
+    #[version_rename("v2_0_0", DEFAULT_LEVEL)]
+    pub const DEFAULT_VERBOSITY: u8 = 1;
+    #[version_rename("v2_0_0", level)]
+    pub fn verbosity() -> u8 { 2 }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    let old = v1_0_0::Config { verbose: true };
+    let new = v2_0_0::Settings { verbose: false };
+    let level: v2_0_0::Level = 3;
+    let verbosity: v1_0_0::Verbosity = level;
+    println!("{} -> {} ({verbosity})", old.verbose, new.verbose);
+    assert_eq!(v1_0_0::DEFAULT_VERBOSITY, v2_0_0::DEFAULT_LEVEL);
+    assert_eq!(v1_0_0::verbosity(), v2_0_0::level());
+}
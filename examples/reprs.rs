@@ -0,0 +1,66 @@
+//  REPRS.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 08:41:01
+//  Last edited:
+//    14 Oct 2026, 08:41:01
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how attributes, and representations in particular, can be
+//!   applied to specific versions only.
+//
+
+use std::mem::size_of;
+
+
+/***** LIBRARY *****/
+mod opcodes {
+    use versioning::versioning;
+
+    /// The enum grew too large for a byte in v2
+    #[versioning(v1_0_0, v2_0_0)]
+    #[derive(Clone, Copy, Debug)]
+    #[repr(u8)]
+    #[version_attr("v2_0_0", repr(u16))]
+    pub enum Opcode {
+        Nop = 0,
+        Jump = 1,
+        #[version("v2_0_0")]
+        Call = 256,
+    }
+}
+
+mod headers {
+    use versioning::versioning;
+
+    /// Any other attribute can be injected too
+    #[versioning(v1_0_0, v2_0_0)]
+    #[derive(Debug)]
+    #[version_attr(min("v2_0_0"), derive(Clone, PartialEq), repr(C))]
+    pub struct Header {
+        pub opcode: u8,
+        pub size:   u32,
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    assert_eq!(size_of::<opcodes::v1_0_0::Opcode>(), 1);
+    assert_eq!(size_of::<opcodes::v2_0_0::Opcode>(), 2);
+    for opcode in [opcodes::v2_0_0::Opcode::Nop, opcodes::v2_0_0::Opcode::Jump, opcodes::v2_0_0::Opcode::Call] {
+        println!("{opcode:?} = {}", opcode as u16);
+    }
+    println!("{:?} {:?}", opcodes::v1_0_0::Opcode::Nop, opcodes::v1_0_0::Opcode::Jump);
+
+    let old = headers::v1_0_0::Header { opcode: 1, size: 8 };
+    let new = headers::v2_0_0::Header { opcode: 1, size: 8 };
+    assert_eq!(new.clone(), new);
+    println!("{} {} -> {} {}", old.opcode, old.size, new.opcode, new.size);
+}
@@ -0,0 +1,56 @@
+//  REQUIRE VERSION.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 09:23:55
+//  Last edited:
+//    14 Oct 2026, 09:26:18
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to require an explicit `#[version(...)]` on everything,
+//!   which avoids forgetting to annotate new fields.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+#[versioning(v1_0_0, v2_0_0, require_version = true)]
+mod defs {
+    /// Things that exist in all versions say so explicitly
+    #[version(default)]
+    pub struct Config {
+        #[version(default)]
+        pub name: String,
+        #[version("v2_0_0")]
+        pub port: u16,
+    }
+
+    #[version(default)]
+    pub enum Mode {
+        #[version(default)]
+        Fast,
+        #[version("v2_0_0")]
+        Safe(#[version(default)] u8),
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    let a = v1_0_0::Config { name: "a".into() };
+    let b = v2_0_0::Config { name: "b".into(), port: 42 };
+    println!("{} | {} {}", a.name, b.name, b.port);
+    for mode in [v2_0_0::Mode::Fast, v2_0_0::Mode::Safe(1)] {
+        match mode {
+            v2_0_0::Mode::Fast => println!("fast"),
+            v2_0_0::Mode::Safe(level) => println!("safe {level}"),
+        }
+    }
+    let v1_0_0::Mode::Fast = v1_0_0::Mode::Fast;
+}
@@ -0,0 +1,63 @@
+//  SCHEMAS.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:25:32
+//  Last edited:
+//    14 Oct 2026, 10:50:42
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to derive a JSON Schema for every version, and how to get
+//!   it through the generated `schema()`-functions. Requires the
+//!   `schemars` feature.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+mod config {
+    use super::versioning;
+
+    /// Generates `v1_0_0::schema()` and `v2_0_0::schema()`, where the derive is injected before `#[schemars(...)]`
+    #[versioning(v1_0_0, v2_0_0, schemars = true, schema_fn = true, pass_attrs(allow(dead_code)))]
+    #[schemars(title = "Settings")]
+    #[derive(Debug)]
+    pub struct Config {
+        pub name: String,
+        #[version("v2_0_0")]
+        pub port: u16,
+    }
+}
+
+mod wrapper {
+    use super::versioning;
+
+    /// Generic items take the same generics, e.g., `v1_0_0::schema::<u64>()`
+    #[versioning(v1_0_0, v2_0_0, schemars = true, schema_fn = true, pass_attrs(allow(dead_code)))]
+    pub struct Wrapper<T> {
+        pub value: T,
+        #[version("v2_0_0")]
+        pub count: usize,
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    let v1 = config::v1_0_0::schema();
+    let v2 = config::v2_0_0::schema();
+    let props = |schema: &schemars::schema::RootSchema| -> Vec<String> {
+        schema.schema.object.as_ref().map(|obj| obj.properties.keys().cloned().collect()).unwrap_or_default()
+    };
+    assert_eq!(props(&v1), ["name"]);
+    assert_eq!(props(&v2), ["name", "port"]);
+    assert_eq!(v1.schema.metadata.as_ref().and_then(|meta| meta.title.as_deref()), Some("Settings"));
+    assert_eq!(props(&wrapper::v1_0_0::schema::<u64>()), ["value"]);
+    assert_eq!(props(&wrapper::v2_0_0::schema::<u64>()), ["count", "value"]);
+}
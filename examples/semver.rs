@@ -0,0 +1,40 @@
+//  SEMVER.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 08:51:37
+//  Last edited:
+//    14 Oct 2026, 08:51:37
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how the list of versions can be derived from a semantic
+//!   versioning range. Requires the `semver` feature.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+/// Walks the range per minor version, generating `v1_0_0`, `v1_1_0` and `v1_2_0`
+#[versioning(semver = ">=1.0.0, <1.3.0", step = "minor")]
+mod defs {
+    pub struct Example {
+        pub name: String,
+        #[version(min("v1_2_0"))]
+        pub tags: Vec<String>,
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    let a = v1_0_0::Example { name: "a".into() };
+    let b = v1_1_0::Example { name: "b".into() };
+    let c = v1_2_0::Example { name: "c".into(), tags: vec!["new".into()] };
+    println!("{} {} {} {:?}", a.name, b.name, c.name, c.tags);
+}
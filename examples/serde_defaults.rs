@@ -0,0 +1,46 @@
+//  SERDE DEFAULTS.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:23:57
+//  Last edited:
+//    14 Oct 2026, 10:24:11
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how `serde_defaults` lets newer versions deserialize payloads
+//!   of older ones, by defaulting the fields those don't have yet.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+#[versioning(v1_0_0, v2_0_0, serde_defaults = true)]
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct Config {
+    pub name: String,
+    /// Gets a `#[serde(default)]` in `v2_0_0`, as it's missing in `v1_0_0`
+    #[version("v2_0_0")]
+    pub port: u16,
+    /// Fields that already say how to default them are left alone (note the path is from the version module)
+    #[version("v2_0_0")]
+    #[serde(default = "super::default_host")]
+    pub host: String,
+}
+
+/// Provides the default for [`Config::host`].
+fn default_host() -> String { "localhost".into() }
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    let old: String = serde_json::to_string(&v1_0_0::Config { name: "server".into() }).unwrap();
+    let new: v2_0_0::Config = serde_json::from_str(&old).unwrap();
+    println!("{old} -> {new:?}");
+    assert_eq!((new.name.as_str(), new.port, new.host.as_str()), ("server", 0, "localhost"));
+}
@@ -4,7 +4,7 @@
 //  Created:
 //    18 Nov 2023, 13:06:17
 //  Last edited:
-//    21 Dec 2023, 10:47:42
+//    14 Oct 2026, 09:01:45
 //  Auto updated?
 //    Yes
 //
@@ -68,6 +68,14 @@ pub mod take4 {
     }
 }
 
+pub mod take5 {
+    use versioning::versioning;
+
+    #[versioning(v1_0_0, v2_0_0, v3_0_0)]
+    #[version(none("v1_0_0", "v2_0_0"))]
+    pub struct FileDefinition5 {}
+}
+
 
 
 
@@ -80,4 +88,7 @@ fn main() {
     // Also works with impls, if annotated correctly :)
     let _b = take4::v1_0_0::FileDefinition4a::new();
     let _c = take4::v2_0_0::FileDefinition4b {};
+
+    // `none(...)` excludes a set of versions
+    let _d = take5::v3_0_0::FileDefinition5 {};
 }
@@ -0,0 +1,54 @@
+//  SIZE ASSERTS.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 09:23:08
+//  Last edited:
+//    14 Oct 2026, 09:23:08
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to assert the size of generated structs and enums at
+//!   compile time, which catches layout changes in frozen versions.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+#[versioning(v1_0_0, v2_0_0, v3_0_0, size_asserts = true, pass_attrs(allow(dead_code)))]
+mod defs {
+    /// The first two versions are frozen, while the last one may still change
+    #[repr(C)]
+    #[version_size("v1_0_0", 4)]
+    #[version_size("v2_0_0", 8)]
+    pub struct Header {
+        pub magic:  u32,
+        #[version(min("v2_0_0"))]
+        pub length: u32,
+        #[version("v3_0_0")]
+        pub flags:  u64,
+    }
+
+    #[repr(u8)]
+    #[version_size(any("v1_0_0", "v2_0_0", "v3_0_0"), 1)]
+    pub enum Kind {
+        Request,
+        Response,
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    // Changing any of the fields of `Header` in the first two versions now fails to compile
+    let a = v1_0_0::Header { magic: 42 };
+    let b = v2_0_0::Header { magic: 42, length: 0 };
+    let c = v3_0_0::Header { magic: 42, length: 0, flags: 0 };
+    println!("{} | {} {} | {} {} {}", a.magic, b.magic, b.length, c.magic, c.length, c.flags);
+    println!("{} {} {}", v1_0_0::Kind::Request as u8, v2_0_0::Kind::Response as u8, v3_0_0::Kind::Request as u8);
+}
@@ -0,0 +1,70 @@
+//  SOURCE_ORDER.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 08:43:00
+//  Last edited:
+//    14 Oct 2026, 08:43:00
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Asserts that fields and variants surviving the filter keep their
+//!   relative order in the source.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+// The fields are only read through `Debug`, so silence the dead code warnings
+#[versioning(v1_0_0, v2_0_0, v3_0_0, pass_attrs(allow(dead_code)))]
+mod defs {
+    /// Gated and ungated fields are mixed, with an ungated one last to test trailing comma handling
+    #[derive(Debug)]
+    pub struct Example {
+        pub a: u8,
+        #[version("v1_0_0")]
+        pub b: u8,
+        pub c: u8,
+        #[version(min("v2_0_0"))]
+        pub d: u8,
+        #[version(any("v1_0_0", "v3_0_0"))]
+        pub e: u8,
+        pub f: u8,
+    }
+
+    /// Implicit discriminants count up in source order, so they reveal the order of the generated variants
+    #[derive(Clone, Copy, Debug)]
+    pub enum Order {
+        #[version("v2_0_0")]
+        A,
+        B,
+        #[version(not("v2_0_0"))]
+        C,
+        D,
+        #[version(min("v2_0_0"))]
+        E,
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    // Debug prints the fields in the order they are generated
+    let v1 = v1_0_0::Example { a: 0, b: 1, c: 2, e: 4, f: 5 };
+    let v2 = v2_0_0::Example { a: 0, c: 2, d: 3, f: 5 };
+    let v3 = v3_0_0::Example { a: 0, c: 2, d: 3, e: 4, f: 5 };
+    assert_eq!(format!("{v1:?}"), "Example { a: 0, b: 1, c: 2, e: 4, f: 5 }");
+    assert_eq!(format!("{v2:?}"), "Example { a: 0, c: 2, d: 3, f: 5 }");
+    assert_eq!(format!("{v3:?}"), "Example { a: 0, c: 2, d: 3, e: 4, f: 5 }");
+
+    // The variants fill up the discriminants in order
+    assert_eq!([v1_0_0::Order::B as u8, v1_0_0::Order::C as u8, v1_0_0::Order::D as u8], [0, 1, 2]);
+    assert_eq!([v2_0_0::Order::A as u8, v2_0_0::Order::B as u8, v2_0_0::Order::D as u8, v2_0_0::Order::E as u8], [0, 1, 2, 3]);
+    assert_eq!([v3_0_0::Order::B as u8, v3_0_0::Order::C as u8, v3_0_0::Order::D as u8, v3_0_0::Order::E as u8], [0, 1, 2, 3]);
+    println!("{:?}", v1_0_0::Order::B);
+}
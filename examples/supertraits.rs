@@ -0,0 +1,60 @@
+//  SUPERTRAITS.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 09:04:56
+//  Last edited:
+//    14 Oct 2026, 09:05:04
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to add supertraits to a trait in some versions only.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+#[versioning(v1_0_0, v2_0_0, pass_attrs(allow(dead_code)))]
+mod defs {
+    // Only requires `Debug` from v2 onwards
+    #[version_supertrait(min("v2_0_0"), std::fmt::Debug)]
+    pub trait Named {
+        fn name(&self) -> String;
+    }
+
+    // Supertraits that are always there are kept, too
+    #[version_supertrait("v2_0_0", std::fmt::Debug)]
+    pub trait Cloned: Clone {
+        fn cloned(&self) -> Self { self.clone() }
+    }
+}
+
+
+
+/// Doesn't implement `Debug`, so it can only implement v1 of [`Named`].
+struct Opaque;
+impl v1_0_0::Named for Opaque {
+    fn name(&self) -> String { "opaque".into() }
+}
+
+/// Does implement `Debug`, so it can implement v2 as well.
+#[derive(Clone, Debug)]
+struct Transparent;
+impl v2_0_0::Named for Transparent {
+    fn name(&self) -> String { format!("{self:?}") }
+}
+impl v2_0_0::Cloned for Transparent {}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    use v1_0_0::Named as _;
+    use v2_0_0::{Cloned as _, Named as _};
+
+    println!("{} {} {:?}", Opaque.name(), Transparent.name(), Transparent.cloned());
+}
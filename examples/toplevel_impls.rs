@@ -0,0 +1,58 @@
+//  TOPLEVEL IMPLS.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 09:44:23
+//  Last edited:
+//    14 Oct 2026, 09:44:23
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to use `#[versioning(...)]` directly on an `impl` block,
+//!   e.g., to implement every version of a trait for a type that isn't
+//!   versioned itself.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+/// A versioned API.
+mod api {
+    use super::versioning;
+
+    #[versioning(v1_0_0, v2_0_0)]
+    pub mod defs {
+        pub trait Handler {
+            fn handle(&self, input: &str) -> String;
+            #[version("v2_0_0")]
+            fn name(&self) -> &'static str;
+        }
+    }
+}
+
+/// A type implementing every version of it.
+pub struct Server;
+
+/// Generates an (otherwise empty) module per version with the impl for that version in it. As such, paths are resolved from
+/// within those modules.
+#[versioning(v1_0_0, v2_0_0)]
+#[version_impl_trait("v2_0_0", crate::api::v2_0_0::Handler)]
+impl crate::api::v1_0_0::Handler for crate::Server {
+    fn handle(&self, input: &str) -> String { input.to_uppercase() }
+    #[version("v2_0_0")]
+    fn name(&self) -> &'static str { "server" }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    assert_eq!(api::v1_0_0::Handler::handle(&Server, "hello"), "HELLO");
+    assert_eq!(api::v2_0_0::Handler::handle(&Server, "world"), "WORLD");
+    assert_eq!(api::v2_0_0::Handler::name(&Server), "server");
+    println!("{}", api::v2_0_0::Handler::name(&Server));
+}
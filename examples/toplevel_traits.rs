@@ -0,0 +1,92 @@
+//  TOPLEVEL TRAITS.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 09:48:36
+//  Last edited:
+//    14 Oct 2026, 09:48:36
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to use `#[versioning(...)]` directly on a trait, of which
+//!   the methods grow across versions.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+/// Generates `v1_0_0::Api` up to `v3_0_0::Api`, each with the methods of that version.
+#[versioning(v1_0_0, v2_0_0, v3_0_0)]
+pub trait Api {
+    fn get(&self, key: &str) -> Option<String>;
+
+    #[version(min("v2_0_0"))]
+    fn set(&mut self, key: &str, value: String);
+
+    #[version(min("v3_0_0"))]
+    fn remove(&mut self, key: &str) -> Option<String>;
+}
+
+/// Private traits are made public in their version module, which is private itself (so they're usable next to it)...
+mod internal {
+    use super::versioning;
+
+    #[versioning(v1_0_0, v2_0_0)]
+    trait Internal {
+        #[version("v2_0_0")]
+        fn internal(&self) -> u32 { 42 }
+    }
+
+    impl v2_0_0::Internal for () {}
+    pub fn check() -> u32 { v2_0_0::Internal::internal(&()) }
+}
+
+/// ...while other visibilities are kept, for the trait and its version modules alike.
+mod restricted {
+    use super::versioning;
+
+    #[versioning(v1_0_0, v2_0_0)]
+    pub(crate) trait Restricted {
+        fn name(&self) -> &'static str { "restricted" }
+
+        #[version("v2_0_0")]
+        fn restricted(&self) -> u32 { 84 }
+    }
+}
+
+#[derive(Default)]
+struct Store(std::collections::HashMap<String, String>);
+impl v1_0_0::Api for Store {
+    fn get(&self, key: &str) -> Option<String> { self.0.get(key).cloned() }
+}
+impl v3_0_0::Api for Store {
+    fn get(&self, key: &str) -> Option<String> { self.0.get(key).cloned() }
+
+    fn set(&mut self, key: &str, value: String) { self.0.insert(key.into(), value); }
+
+    fn remove(&mut self, key: &str) -> Option<String> { self.0.remove(key) }
+}
+impl restricted::v1_0_0::Restricted for Store {}
+impl restricted::v2_0_0::Restricted for Store {}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    use v3_0_0::Api as _;
+
+    let mut store = Store::default();
+    store.set("hello", "world".into());
+    assert_eq!(v1_0_0::Api::get(&store, "hello"), Some("world".into()));
+    assert_eq!(store.remove("hello"), Some("world".into()));
+    assert_eq!(store.get("hello"), None);
+
+    assert_eq!(internal::check(), 42);
+    assert_eq!(restricted::v1_0_0::Restricted::name(&store), "restricted");
+    assert_eq!(restricted::v2_0_0::Restricted::name(&store), "restricted");
+    assert_eq!(restricted::v2_0_0::Restricted::restricted(&store), 84);
+}
@@ -0,0 +1,84 @@
+//  TOPLEVEL USES.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 09:49:31
+//  Last edited:
+//    14 Oct 2026, 09:49:31
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to version a single `use`-statement, generating a facade
+//!   module per version that re-exports a different upstream path.
+//
+
+use versioning::versioning;
+
+
+/***** DEPENDENCIES *****/
+/// Where `Client` used to live...
+mod legacy {
+    pub struct Client;
+    impl Client {
+        pub fn name(&self) -> &'static str { "legacy" }
+    }
+}
+
+/// ...and where it lives now
+mod modern {
+    pub struct Client;
+    impl Client {
+        pub fn name(&self) -> &'static str { "modern" }
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Generates `v1_0_0::Client` and `v2_0_0::Client`, which are different types.
+///
+/// Note that the paths are resolved from within the generated version modules, so they're best written from `crate` (or
+/// `super`).
+#[versioning(v1_0_0, v2_0_0)]
+#[version_use("v2_0_0", crate::modern::Client)]
+pub use crate::legacy::Client;
+
+/// Private imports are still visible next to their version modules...
+mod private {
+    use super::versioning;
+
+    struct Secret;
+    impl Secret {
+        fn name(&self) -> &'static str { "secret" }
+    }
+
+    #[versioning(v1_0_0, v2_0_0)]
+    #[version_use("v2_0_0", crate::modern::Client as Secret)]
+    use super::Secret;
+
+    pub fn check() -> (&'static str, &'static str) { (v1_0_0::Secret.name(), v2_0_0::Secret.name()) }
+}
+
+/// ...while restricted ones keep their visibility.
+mod restricted {
+    use super::versioning;
+    #[versioning(v1_0_0, v2_0_0)]
+    #[version_use("v2_0_0", crate::modern::Client as Restricted)]
+    pub(crate) use crate::legacy::Client as Restricted;
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    assert_eq!(v1_0_0::Client.name(), "legacy");
+    assert_eq!(v2_0_0::Client.name(), "modern");
+    assert_eq!(private::check(), ("secret", "modern"));
+    assert_eq!(restricted::v1_0_0::Restricted.name(), "legacy");
+    assert_eq!(restricted::v2_0_0::Restricted.name(), "modern");
+}
@@ -0,0 +1,48 @@
+//  TOPLEVEL VALUES.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 09:46:52
+//  Last edited:
+//    14 Oct 2026, 09:46:52
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to use `#[versioning(...)]` directly on a constant or a
+//!   static, of which the value differs per version.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+/// Generates `v1_0_0::LIMIT` up to `v3_0_0::LIMIT`. Since they're in modules, private constants are made public to be usable
+/// next to them (use `force_public = false` to prevent this).
+#[versioning(v1_0_0, v2_0_0, v3_0_0)]
+#[version_value(min("v2_0_0"), 64)]
+const LIMIT: usize = 16;
+
+/// The same goes for statics, which keep their visibility if it's not private.
+mod statics {
+    use super::versioning;
+
+    #[versioning(v1_0_0, v2_0_0)]
+    #[version_value("v2_0_0", "/api/v2")]
+    pub(crate) static PREFIX: &str = "/api";
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    assert_eq!(v1_0_0::LIMIT, 16);
+    assert_eq!(v2_0_0::LIMIT, 64);
+    assert_eq!(v3_0_0::LIMIT, 64);
+
+    assert_eq!(statics::v1_0_0::PREFIX, "/api");
+    assert_eq!(statics::v2_0_0::PREFIX, "/api/v2");
+    println!("{} {}", v3_0_0::LIMIT, statics::v2_0_0::PREFIX);
+}
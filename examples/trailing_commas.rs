@@ -0,0 +1,51 @@
+//  TRAILING COMMAS.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 09:34:58
+//  Last edited:
+//    14 Oct 2026, 09:34:58
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows that trailing commas are accepted everywhere in version
+//!   filters, like in the rest of Rust.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+#[versioning(v1_0_0, v2_0_0, v3_0_0)]
+mod defs {
+    pub struct Example {
+        #[version("v1_0_0")]
+        pub a: bool,
+        #[version(any("v1_0_0", "v2_0_0",))]
+        pub b: bool,
+        #[version(all(min("v2_0_0",), not("v3_0_0",),))]
+        pub c: bool,
+        #[version(window("v2_0_0", 2,))]
+        pub d: bool,
+        #[version(since = "v1_0_0", until = "v3_0_0")]
+        pub e: bool,
+        #[version_attr("v3_0_0", allow(unused))]
+        #[version(none("v1_0_0",))]
+        pub f: bool,
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    let v1 = v1_0_0::Example { a: true, b: true, e: true };
+    let v2 = v2_0_0::Example { b: true, c: true, d: true, e: true, f: true };
+    let v3 = v3_0_0::Example { d: true, f: true };
+    println!("{} {} {}", v1.a, v1.b, v1.e);
+    println!("{} {} {} {} {}", v2.b, v2.c, v2.d, v2.e, v2.f);
+    println!("{} {}", v3.d, v3.f);
+}
@@ -0,0 +1,63 @@
+//  TRAIT_ALIASES.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 08:31:40
+//  Last edited:
+//    14 Oct 2026, 08:31:40
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows versioning of trait aliases.
+//!
+//!   Note that these are still unstable, so this example requires a
+//!   nightly compiler and the `nightly`-feature, e.g.,
+//!   `cargo +nightly run --example trait_aliases --features nightly`.
+//
+
+#![feature(trait_alias)]
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+#[versioning(v1_0_0, v2_0_0)]
+mod defs {
+    /// Only exists in the second version
+    #[version("v2_0_0")]
+    pub trait Bound = Clone + Send;
+
+    /// The where-clause is kept too
+    pub(crate) trait Container<T> = IntoIterator<Item = T> where T: Clone;
+
+    /// Aliases without any bounds
+    #[version("v2_0_0")]
+    pub trait Anything = where Self: Sized;
+}
+
+/// Toplevel aliases are wrapped in a module with the alias' visibility (and made public inside of it)
+mod toplevel {
+    use versioning::versioning;
+
+    #[versioning(v1_0_0, v2_0_0)]
+    #[version("v2_0_0")]
+    pub(crate) trait Ordered = Ord + Eq;
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn assert_bound<T: v2_0_0::Bound>(_: T) {}
+fn assert_container<C: v1_0_0::Container<u64>>(_: C) {}
+fn assert_anything<T: v2_0_0::Anything>(_: T) {}
+fn assert_ordered<T: toplevel::v2_0_0::Ordered>(_: T) {}
+
+fn main() {
+    assert_bound(42u64);
+    assert_container(vec![1, 2, 3]);
+    assert_anything("Hello, world!");
+    assert_ordered(42u64);
+}
@@ -0,0 +1,43 @@
+//  TYPE SUFFIX.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 09:11:37
+//  Last edited:
+//    14 Oct 2026, 10:42:03
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to suffix the generated items with their version, which
+//!   avoids name clashes when importing them from multiple versions.
+//!   Note that this qualifies them twice, e.g., `v1_0_0::ConfigV1_0_0`,
+//!   and that it can only be used on type-like items (e.g., not on
+//!   modules, constants or functions).
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+#[versioning(v1_0_0, v2_0_0, type_suffix = true)]
+pub struct Config {
+    pub name: String,
+    #[version("v2_0_0")]
+    pub port: u16,
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    // Both can be imported side-by-side
+    use v1_0_0::ConfigV1_0_0;
+    use v2_0_0::ConfigV2_0_0;
+
+    let old = ConfigV1_0_0 { name: "old".into() };
+    let new = ConfigV2_0_0 { name: "new".into(), port: 42 };
+    println!("{} {}:{}", old.name, new.name, new.port);
+}
@@ -0,0 +1,81 @@
+//  TYPES.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 08:30:27
+//  Last edited:
+//    14 Oct 2026, 09:47:12
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how type aliases and fields can have a different type per
+//!   version, including aliases that `#[versioning(...)]` is used on
+//!   directly.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+#[versioning(v1_0_0, v2_0_0)]
+mod defs {
+    /// Centralizes a primitive that was widened in `v2_0_0`
+    #[version_type("v2_0_0", u64)]
+    pub type Id = u32;
+
+    /// Also works for aliases with generics
+    #[version_type("v2_0_0", std::collections::HashMap<Id, V>)]
+    pub type Table<V> = Vec<(Id, V)>;
+
+    pub struct User {
+        pub id:   Id,
+        /// Fields can be overridden too
+        #[version_type("v2_0_0", Option<String>)]
+        pub name: String,
+    }
+}
+
+/// Works for a single alias too, which is wrapped in a module per version with the same visibility as the alias.
+mod toplevel {
+    use super::versioning;
+
+    #[versioning(v1_0_0, v2_0_0)]
+    #[version_type("v2_0_0", u64)]
+    pub type Id = u32;
+}
+
+/// Including ones with generics, where the overridden target may use them too.
+mod generic {
+    use super::versioning;
+
+    #[versioning(v1_0_0, v2_0_0)]
+    #[version_type("v2_0_0", Result<T, String>)]
+    pub type Outcome<T> = Option<T>;
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    let a: v1_0_0::Id = u32::MAX;
+    let b: v2_0_0::Id = u64::MAX;
+    println!("{a} {b}");
+    let a: toplevel::v1_0_0::Id = u32::MAX;
+    let b: toplevel::v2_0_0::Id = u64::MAX;
+    println!("{a} {b}");
+    let a: generic::v1_0_0::Outcome<u8> = Some(1);
+    let b: generic::v2_0_0::Outcome<u8> = Err("failed".into());
+    println!("{a:?} {b:?}");
+
+    let users: v1_0_0::Table<v1_0_0::User> = vec![(1, v1_0_0::User { id: 1, name: "Amy".into() })];
+    for (id, user) in users {
+        println!("{id} {} {}", user.id, user.name);
+    }
+    let users: v2_0_0::Table<v2_0_0::User> = [(1, v2_0_0::User { id: 1, name: None })].into_iter().collect();
+    for (id, user) in users {
+        println!("{id} {} {:?}", user.id, user.name);
+    }
+}
@@ -0,0 +1,70 @@
+//  UNIT_STRUCTS.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 08:41:32
+//  Last edited:
+//    14 Oct 2026, 10:21:22
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how structs that lose all their fields in a version can be
+//!   generated as unit structs.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+#[versioning(v1_0_0, v2_0_0, unit_structs = true)]
+mod defs {
+    /// In v1, this struct has no fields left, so it becomes `struct Marker;`
+    pub struct Marker {
+        #[version("v2_0_0")]
+        pub id: u64,
+    }
+
+    /// The same goes for tuple structs
+    pub struct Wrapper(#[version("v2_0_0")] pub String);
+
+    /// Structs that are declared without fields are kept as-is
+    pub struct Empty {}
+}
+
+/// Generated implementations construct the collapsed structs as unit structs too
+mod defaults {
+    use super::versioning;
+
+    #[versioning(v1_0_0, v2_0_0, unit_structs = true, default = true)]
+    pub mod defs {
+        pub struct Marker {
+            #[version("v2_0_0")]
+            pub id: u64,
+        }
+        pub struct Wrapper(#[version("v2_0_0")] pub String);
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    // Unit structs can be used as values directly
+    let _: v1_0_0::Marker = v1_0_0::Marker;
+    let _: v1_0_0::Wrapper = v1_0_0::Wrapper;
+    let _: v1_0_0::Empty = v1_0_0::Empty {};
+
+    let marker = v2_0_0::Marker { id: 42 };
+    let wrapper = v2_0_0::Wrapper("Hello, world!".into());
+    let _: v2_0_0::Empty = v2_0_0::Empty {};
+    println!("{} {}", marker.id, wrapper.0);
+
+    let _: defaults::v1_0_0::Marker = Default::default();
+    let _: defaults::v1_0_0::Wrapper = Default::default();
+    let marker: defaults::v2_0_0::Marker = Default::default();
+    let wrapper: defaults::v2_0_0::Wrapper = Default::default();
+    println!("{} {:?}", marker.id, wrapper.0);
+}
@@ -0,0 +1,56 @@
+//  UNSAFETY.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 08:46:47
+//  Last edited:
+//    14 Oct 2026, 08:46:47
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how functions can be `unsafe` in some versions only.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+#[versioning(v1_0_0, v2_0_0, v3_0_0)]
+mod defs {
+    /// The contract of this function was loosened in v3, making it `unsafe`; and it became `const` in v2
+    ///
+    /// # Safety
+    /// In v3, the caller must ensure that `index` is in bounds.
+    #[version_unsafe("v3_0_0")]
+    #[version_constness(min("v2_0_0"))]
+    pub fn get(values: &[u8], index: usize) -> u8 { values[index] }
+
+    /// It composes with other qualifiers too
+    ///
+    /// # Safety
+    /// In v3, the caller must ensure that `index` is in bounds.
+    #[version_unsafe("v3_0_0")]
+    pub async fn fetch(values: &[u8], index: usize) -> u8 { values[index] }
+}
+
+/// Only v2 is `const` but not `unsafe`
+const SECOND: u8 = v2_0_0::get(&[1, 2, 3], 1);
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    let values: [u8; 3] = [1, 2, 3];
+    assert_eq!(v1_0_0::get(&values, 1), SECOND);
+    // SAFETY: The index is in bounds
+    assert_eq!(unsafe { v3_0_0::get(&values, 1) }, SECOND);
+
+    // Futures that are never polled; we're interested in them compiling
+    drop(v1_0_0::fetch(&values, 0));
+    drop(v2_0_0::fetch(&values, 0));
+    // SAFETY: The index is in bounds
+    drop(unsafe { v3_0_0::fetch(&values, 0) });
+}
@@ -0,0 +1,79 @@
+//  USES.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 08:30:46
+//  Last edited:
+//    14 Oct 2026, 09:10:03
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how `use`-statements can import a different path per version.
+//
+
+use versioning::versioning;
+
+
+/***** DEPENDENCIES *****/
+/// Where `Thing` used to live...
+mod old {
+    pub struct Thing;
+    impl Thing {
+        pub fn name(&self) -> &'static str { "old" }
+    }
+}
+
+/// Some things that are imported together...
+mod shapes {
+    pub struct Circle;
+    pub struct Square;
+    pub struct Triangle;
+}
+
+/// ...and where it was moved to
+mod new {
+    pub mod path {
+        pub struct Thing;
+        impl Thing {
+            pub fn name(&self) -> &'static str { "new" }
+        }
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+#[versioning(v1_0_0, v2_0_0)]
+mod defs {
+    /// The declared path is used unless a `#[version_use(...)]` matches (which replaces the whole tree, including any leading `::`)
+    #[version_use("v2_0_0", crate::new::path::Thing)]
+    pub use crate::old::Thing;
+    /// Names in a group can be imported in some versions only, too (the others are always imported)
+    #[version_use_item("v2_0_0", Triangle)]
+    pub use crate::shapes::{Circle, Square, Triangle};
+
+    /// Which also allows switching between a curated list and a glob
+    pub mod prelude {
+        #[version_use("v1_0_0", crate::shapes::{Circle, Square})]
+        pub use crate::shapes::*;
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    assert_eq!(v1_0_0::Thing.name(), "old");
+    assert_eq!(v2_0_0::Thing.name(), "new");
+
+    // Only v2 has triangles
+    let _ = (v1_0_0::Circle, v1_0_0::Square);
+    let _ = (v2_0_0::Circle, v2_0_0::Square, v2_0_0::Triangle);
+    let _ = (v1_0_0::prelude::Circle, v1_0_0::prelude::Square);
+    let _ = (v2_0_0::prelude::Circle, v2_0_0::prelude::Square, v2_0_0::prelude::Triangle);
+}
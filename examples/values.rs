@@ -0,0 +1,71 @@
+//  VALUES.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 08:29:54
+//  Last edited:
+//    14 Oct 2026, 09:40:04
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how constants and statics can have a different value per
+//!   version, as can the defaults of associated constants in traits.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+#[versioning(v1_0_0, v2_0_0, v3_0_0)]
+mod defs {
+    /// The declared value is the default, which is overridden for the versions matching a `#[version_value(...)]`
+    #[version_value(min("v2_0_0"), 8)]
+    pub const MAX: usize = 4;
+
+    /// Works for statics too (where the first match wins)
+    #[version_value("v3_0_0", "Hello, version three!")]
+    #[version_value(min("v2_0_0"), "Hello, version two!")]
+    pub static GREETING: &str = "Hello, version one!";
+
+    pub trait Limits {
+        /// Works for the defaults of associated constants too
+        #[version_value("v3_0_0", 16)]
+        const CAPACITY: usize = 4;
+
+        /// Which may also only be provided in some versions (and be required in the others)
+        #[version_provided(max("v2_0_0"))]
+        #[version_value("v2_0_0", 2)]
+        const RETRIES: usize = 1;
+    }
+}
+
+/// Only has to give the constants that don't have a default.
+struct Defaults;
+impl v1_0_0::Limits for Defaults {}
+impl v2_0_0::Limits for Defaults {}
+impl v3_0_0::Limits for Defaults {
+    const RETRIES: usize = 3;
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    assert_eq!(v1_0_0::MAX, 4);
+    assert_eq!(v2_0_0::MAX, 8);
+    assert_eq!(v3_0_0::MAX, 8);
+
+    assert_eq!(v1_0_0::GREETING, "Hello, version one!");
+    assert_eq!(v2_0_0::GREETING, "Hello, version two!");
+    assert_eq!(v3_0_0::GREETING, "Hello, version three!");
+
+    assert_eq!(<Defaults as v1_0_0::Limits>::CAPACITY, 4);
+    assert_eq!(<Defaults as v2_0_0::Limits>::CAPACITY, 4);
+    assert_eq!(<Defaults as v3_0_0::Limits>::CAPACITY, 16);
+    assert_eq!(<Defaults as v1_0_0::Limits>::RETRIES, 1);
+    assert_eq!(<Defaults as v2_0_0::Limits>::RETRIES, 2);
+    assert_eq!(<Defaults as v3_0_0::Limits>::RETRIES, 3);
+}
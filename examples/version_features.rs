@@ -0,0 +1,59 @@
+//  VERSION FEATURES.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 08:55:57
+//  Last edited:
+//    14 Oct 2026, 08:55:57
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to gate (parts of) an item on an additional feature using
+//!   `#[version_feature(...)]`. This crate's `nightly`-feature stands in
+//!   for one of your own; compare the output of
+//!   `cargo run --example version_features` with that of
+//!   `cargo run --example version_features --features nightly`.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+mod config {
+    use super::*;
+
+    #[versioning(v1_0_0, v2_0_0, pass_attrs(allow(dead_code)))]
+    pub struct Config {
+        pub name:     String,
+        /// Only exists if the `nightly`-feature is enabled.
+        #[version_feature("nightly")]
+        pub unstable: bool,
+    }
+}
+
+mod unstable {
+    use super::*;
+
+    #[versioning(v1_0_0, v2_0_0, pass_attrs(allow(dead_code)))]
+    #[version_feature("nightly")]
+    pub fn unstable_name(config: &crate::config::v1_0_0::Config) -> &str { &config.name }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    #[cfg(not(feature = "nightly"))]
+    {
+        let config = config::v2_0_0::Config { name: "stable".into() };
+        println!("{}", config.name);
+    }
+    #[cfg(feature = "nightly")]
+    {
+        let config = config::v1_0_0::Config { name: "nightly".into(), unstable: true };
+        println!("{} {} {}", config.name, config.unstable, unstable::v2_0_0::unstable_name(&config));
+    }
+}
@@ -0,0 +1,107 @@
+//  VISIBILITY.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 08:44:13
+//  Last edited:
+//    14 Oct 2026, 08:45:11
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how the visibility of items is treated when they are wrapped
+//!   in a version module.
+//
+
+
+/***** LIBRARY *****/
+mod api {
+    mod private {
+        use versioning::versioning;
+
+        /// Private items are promoted to `pub`, as the version module itself gets the original (private) visibility
+        #[versioning(v1_0_0, v2_0_0)]
+        fn version() -> u32 { 1 }
+
+        pub(super) fn check() {
+            assert_eq!(v1_0_0::version(), 1);
+            assert_eq!(v2_0_0::version(), 1);
+        }
+    }
+
+    mod internal {
+        use versioning::versioning;
+
+        /// Restricted visibilities with an absolute path are kept as-is
+        #[versioning(v1_0_0, v2_0_0)]
+        pub(in crate::api) struct Internal {
+            pub id: u32,
+        }
+    }
+
+    mod relative {
+        use versioning::versioning;
+
+        /// Relative visibilities are promoted too, as they would otherwise be relative to the version module
+        #[versioning(v1_0_0, v2_0_0)]
+        pub(super) struct Relative {
+            pub id: u32,
+        }
+    }
+
+    mod preserved {
+        use versioning::versioning;
+
+        /// Promotion can be disabled altogether, in which case this is only visible in `preserved` (i.e., the parent of the version modules)
+        #[versioning(v1_0_0, v2_0_0, force_public = false)]
+        pub(super) fn answer() -> u32 { 42 }
+
+        pub(super) fn check() {
+            assert_eq!(v1_0_0::answer(), 42);
+            assert_eq!(v2_0_0::answer(), 42);
+        }
+    }
+
+    /// Items that are `pub(crate)` stay that way, which `unreachable_pub` would complain about otherwise
+    #[deny(unreachable_pub)]
+    mod crate_only {
+        pub(crate) mod counter {
+            use versioning::versioning;
+
+            #[versioning(v1_0_0, v2_0_0)]
+            pub(crate) struct Counter {
+                pub(crate) count: u32,
+                #[version("v2_0_0")]
+                pub(crate) step:  u32,
+            }
+        }
+
+        pub(crate) mod ops {
+            use versioning::versioning;
+
+            #[versioning(v1_0_0, v2_0_0)]
+            pub(crate) fn increment(count: u32) -> u32 { count + 1 }
+        }
+    }
+
+    pub fn check() {
+        private::check();
+        preserved::check();
+        let internal = internal::v1_0_0::Internal { id: 1 };
+        let relative = relative::v2_0_0::Relative { id: 2 };
+        println!("{} {}", internal.id, relative.id);
+        let _ = (internal::v2_0_0::Internal { id: 3 }).id;
+        let _ = (relative::v1_0_0::Relative { id: 4 }).id;
+
+        let old = crate_only::counter::v1_0_0::Counter { count: crate_only::ops::v1_0_0::increment(0) };
+        let new = crate_only::counter::v2_0_0::Counter { count: crate_only::ops::v2_0_0::increment(0), step: 2 };
+        println!("{} {} {}", old.count, new.count, new.step);
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() { api::check(); }
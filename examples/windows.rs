@@ -0,0 +1,46 @@
+//  WINDOWS.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 09:29:37
+//  Last edited:
+//    14 Oct 2026, 09:29:37
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Shows how to use `window(...)` to have a field exist for a fixed
+//!   number of versions.
+//
+
+use versioning::versioning;
+
+
+/***** LIBRARY *****/
+/// `legacy` lives for two versions (`v2_0_0` and `v3_0_0`), while `preview` is declared to live for three but is clamped to the
+/// two versions that exist so far (`v4_0_0` and `v5_0_0`).
+#[versioning(v1_0_0, v2_0_0, v3_0_0, v4_0_0, v5_0_0)]
+mod defs {
+    pub struct Example {
+        pub name:    String,
+        #[version(window("v2_0_0", 2))]
+        pub legacy:  bool,
+        #[version(window("v4_0_0", 3))]
+        pub preview: bool,
+    }
+}
+
+
+
+
+
+/***** ENTRYPOINT *****/
+fn main() {
+    let v1 = v1_0_0::Example { name: "v1".into() };
+    let v2 = v2_0_0::Example { name: "v2".into(), legacy: true };
+    let v3 = v3_0_0::Example { name: "v3".into(), legacy: false };
+    let v4 = v4_0_0::Example { name: "v4".into(), preview: true };
+    let v5 = v5_0_0::Example { name: "v5".into(), preview: false };
+    println!("{} {} {} {} {}", v1.name, v2.name, v3.name, v4.name, v5.name);
+    println!("{} {} {} {}", v2.legacy, v3.legacy, v4.preview, v5.preview);
+}
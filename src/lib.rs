@@ -4,7 +4,7 @@
 //  Created:
 //    18 Nov 2023, 12:57:56
 //  Last edited:
-//    21 Dec 2023, 10:07:20
+//    14 Oct 2026, 10:08:45
 //  Auto updated?
 //    Yes
 //
@@ -13,7 +13,11 @@
 //!   schema- or specification-like struct to multiple versions of itself.
 //
 
+#[cfg(feature = "semver")]
+mod semver;
 // mod spec;
+#[cfg(test)]
+mod tests;
 mod version;
 mod versioning;
 
@@ -38,3 +42,26 @@ pub fn versioning(attr: TokenStream, input: TokenStream) -> TokenStream {
         Err(err) => err.abort(),
     }
 }
+
+/// Defines a stub for the `#[version(...)]`-attribute, which only has meaning inside a `#[versioning(...)]`-region.
+///
+/// Inside such a region it is consumed by [`versioning()`](macro@versioning), so this macro is only ever invoked if it is used
+/// standalone (and imported). It then emits an error explaining that the outer attribute is missing.
+///
+/// # Arguments
+/// - `_attr`: The tokens given in the attribute, i.e., the version filter (ignored).
+/// - `_input`: The tokens that are being attributed (ignored).
+///
+/// # Returns
+/// Never, as it always aborts with an error.
+#[inline]
+#[proc_macro_attribute]
+#[proc_macro_error::proc_macro_error]
+pub fn version(_attr: TokenStream, _input: TokenStream) -> TokenStream {
+    proc_macro_error::Diagnostic::spanned(
+        proc_macro2::Span::call_site(),
+        proc_macro_error::Level::Error,
+        "`#[version]` only has meaning inside a `#[versioning(...)]` block".into(),
+    )
+    .abort()
+}
@@ -0,0 +1,214 @@
+//  SEMVER.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 08:51:37
+//  Last edited:
+//    14 Oct 2026, 08:51:37
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements a small semantic versioning range parser, which is used to
+//!   derive the list of versions from a range instead of listing them.
+//
+
+use std::str::FromStr;
+
+
+/***** CONSTANTS *****/
+/// The maximum number of versions a range may generate, which catches ranges that are unbounded at the chosen granularity.
+const MAX_VERSIONS: usize = 1024;
+
+
+
+
+
+/***** HELPERS *****/
+/// Defines the operator of a single comparator in a range.
+#[derive(Clone, Copy, Debug)]
+enum Op {
+    /// `>`
+    Gt,
+    /// `>=`
+    Ge,
+    /// `<`
+    Lt,
+    /// `<=`
+    Le,
+    /// `=`
+    Eq,
+}
+
+/// Defines a single comparator in a range, e.g., `>=1.0.0`.
+#[derive(Clone, Copy, Debug)]
+struct Comparator {
+    /// The operator to compare with.
+    op:      Op,
+    /// The version to compare with.
+    version: SemVer,
+}
+impl Comparator {
+    /// Checks whether the given version satisfies this comparator.
+    ///
+    /// # Arguments
+    /// - `version`: The [`SemVer`] to check.
+    ///
+    /// # Returns
+    /// True if it does, or false if it doesn't.
+    #[inline]
+    fn matches(&self, version: &SemVer) -> bool {
+        match self.op {
+            Op::Gt => *version > self.version,
+            Op::Ge => *version >= self.version,
+            Op::Lt => *version < self.version,
+            Op::Le => *version <= self.version,
+            Op::Eq => *version == self.version,
+        }
+    }
+}
+impl FromStr for Comparator {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s: &str = s.trim();
+        let (op, version): (Op, &str) = if let Some(version) = s.strip_prefix(">=") {
+            (Op::Ge, version)
+        } else if let Some(version) = s.strip_prefix("<=") {
+            (Op::Le, version)
+        } else if let Some(version) = s.strip_prefix('>') {
+            (Op::Gt, version)
+        } else if let Some(version) = s.strip_prefix('<') {
+            (Op::Lt, version)
+        } else if let Some(version) = s.strip_prefix('=') {
+            (Op::Eq, version)
+        } else {
+            return Err(format!("Comparator '{s}' must start with one of '>=', '>', '<=', '<' or '='"));
+        };
+        Ok(Self { op, version: version.trim().parse()? })
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Defines a semantic version, i.e., a `major.minor.patch` triplet.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct SemVer(pub u64, pub u64, pub u64);
+impl SemVer {
+    /// Returns the next version at the given granularity.
+    ///
+    /// # Arguments
+    /// - `step`: The [`Step`] that determines which number to count up. Any numbers after it are reset to zero.
+    ///
+    /// # Returns
+    /// A new [`SemVer`] that is the next one.
+    #[inline]
+    fn next(&self, step: Step) -> Self {
+        match step {
+            Step::Major => Self(self.0 + 1, 0, 0),
+            Step::Minor => Self(self.0, self.1 + 1, 0),
+            Step::Patch => Self(self.0, self.1, self.2 + 1),
+        }
+    }
+}
+impl FromStr for SemVer {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut nums: [u64; 3] = [0; 3];
+        let mut parts = s.split('.');
+        for num in &mut nums {
+            match parts.next().map(str::parse::<u64>) {
+                Some(Ok(part)) => *num = part,
+                _ => return Err(format!("Version '{s}' is not of the form 'MAJOR.MINOR.PATCH'")),
+            }
+        }
+        if parts.next().is_some() {
+            return Err(format!("Version '{s}' is not of the form 'MAJOR.MINOR.PATCH'"));
+        }
+        Ok(Self(nums[0], nums[1], nums[2]))
+    }
+}
+
+
+
+/// Defines the granularity at which to walk a range.
+#[derive(Clone, Copy, Debug)]
+pub enum Step {
+    /// Counts up the major number, e.g., `1.0.0`, `2.0.0`, ...
+    Major,
+    /// Counts up the minor number, e.g., `1.0.0`, `1.1.0`, ...
+    Minor,
+    /// Counts up the patch number, e.g., `1.0.0`, `1.0.1`, ...
+    Patch,
+}
+impl FromStr for Step {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "major" => Ok(Self::Major),
+            "minor" => Ok(Self::Minor),
+            "patch" => Ok(Self::Patch),
+            other => Err(format!("Unknown step '{other}' (expected 'major', 'minor' or 'patch')")),
+        }
+    }
+}
+
+
+
+/// Walks the given range of semantic versions at the given granularity.
+///
+/// The range is a comma-separated list of comparators (e.g., `>=1.0.0, <2.0.0`), all of which must hold. It must contain at
+/// least one lower bound (`>=`, `>` or `=`), from which the walk starts: `>=` and `=` start at their version, and `>` at the
+/// next version at the given granularity. The walk then counts up (resetting any numbers after the counted one to zero) until
+/// the range no longer holds.
+///
+/// # Arguments
+/// - `range`: The range to walk.
+/// - `step`: The [`Step`] that determines the granularity.
+///
+/// # Returns
+/// A list of [`SemVer`]s in the range, in ascending order.
+///
+/// # Errors
+/// This function errors if the range did not parse, did not have a lower bound, was empty or was unbounded (i.e., generated
+/// more than [`MAX_VERSIONS`] versions).
+pub fn walk(range: &str, step: Step) -> Result<Vec<SemVer>, String> {
+    // Parse the comparators
+    let mut comps: Vec<Comparator> = vec![];
+    for comp in range.split(',') {
+        comps.push(comp.parse()?);
+    }
+
+    // Find where to start
+    let start: Option<SemVer> = comps
+        .iter()
+        .filter_map(|c| match c.op {
+            Op::Ge | Op::Eq => Some(c.version),
+            Op::Gt => Some(c.version.next(step)),
+            Op::Lt | Op::Le => None,
+        })
+        .max();
+    let mut current: SemVer = match start {
+        Some(start) => start,
+        None => return Err(format!("Range '{range}' must have a lower bound (i.e., a '>=', '>' or '=' comparator)")),
+    };
+
+    // Walk it
+    let mut res: Vec<SemVer> = vec![];
+    while comps.iter().all(|c| c.matches(&current)) {
+        if res.len() >= MAX_VERSIONS {
+            return Err(format!("Range '{range}' contains more than {MAX_VERSIONS} versions at the given step (is it bounded?)"));
+        }
+        res.push(current);
+        current = current.next(step);
+    }
+    if res.is_empty() {
+        return Err(format!("Range '{range}' does not contain any versions"));
+    }
+    Ok(res)
+}
@@ -0,0 +1,249 @@
+//  TESTS.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:08:45
+//  Last edited:
+//    14 Oct 2026, 10:48:35
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Snapshots the code generated by the `#[versioning(...)]`-macro.
+//
+
+use quote::{quote, ToTokens as _};
+
+use crate::version::VersionFilter;
+use crate::versioning::expand_to_string;
+
+
+/***** CONSTANTS *****/
+/// Filters that cover every kind of [`VersionFilter`], in both the operator and the infix syntax.
+const FILTERS: [&str; 6] = [
+    r#""v1_0_0""#,
+    r#"window("v1_0_0", 2)"#,
+    r#"none("v1_0_0", max("v2_0_0"))"#,
+    r#"any("v1_0_0", all(min("v1_0_0"), not(mxe("v2_0_0")), mne("v1_0_0")), never)"#,
+    r#""v1_0_0" || !"v2_0_0" && (always || default)"#,
+    r#"not(else)"#,
+];
+
+
+
+
+
+/***** TESTS *****/
+#[test]
+fn test_expand_struct() {
+    let res: String = expand_to_string(
+        r#"v1_0_0, v2_0_0"#,
+        r#"
+            pub struct Config {
+                name: String,
+                #[version("v2_0_0")]
+                port: u16,
+            }
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        res,
+        quote! {
+            pub mod v1_0_0 {
+                pub struct Config {
+                    name: String,
+                }
+            }
+            pub mod v2_0_0 {
+                pub struct Config {
+                    name: String,
+                    port: u16,
+                }
+            }
+        }
+        .to_string()
+    );
+}
+
+#[test]
+fn test_expand_error() {
+    let err: String = expand_to_string(r#"v1_0_0"#, r#"struct Config { #[version("v2_0_0")] port: u16 }"#).unwrap_err();
+    assert!(err.contains("unknown version string 'v2_0_0'"), "{err}");
+}
+
+#[test]
+fn test_expand_non_exhaustive() {
+    let res: String = expand_to_string(
+        r#"v1_0_0, v2_0_0, v3_0_0, non_exhaustive_from = "v2_0_0""#,
+        r#"
+            pub enum Message {
+                Ping,
+            }
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        res,
+        quote! {
+            pub mod v1_0_0 {
+                pub enum Message {
+                    Ping,
+                }
+            }
+            pub mod v2_0_0 {
+                #[non_exhaustive]
+                pub enum Message {
+                    Ping,
+                }
+            }
+            pub mod v3_0_0 {
+                #[non_exhaustive]
+                pub enum Message {
+                    Ping,
+                }
+            }
+        }
+        .to_string()
+    );
+}
+
+#[test]
+fn test_expand_coalesce_docs() {
+    let res: String = expand_to_string(
+        r#"v1_0_0, v2_0_0, coalesce = true"#,
+        r#"
+            #[version_doc("v1_0_0", "Old.")]
+            #[version_doc("v2_0_0", "New.")]
+            pub struct Config;
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        res,
+        quote! {
+            pub mod v1_0_0 {
+                #[doc = "Old."]
+                pub struct Config;
+            }
+            pub mod v2_0_0 {
+                #[doc = "New."]
+                pub struct Config;
+            }
+        }
+        .to_string()
+    );
+}
+
+#[test]
+fn test_expand_ordered_prefix_segments() {
+    let res: String = expand_to_string(
+        r#"v1_0_0, v10_0_0"#,
+        r#"
+            pub struct Config {
+                #[version(max("v1"))]
+                port: u16,
+            }
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        res,
+        quote! {
+            pub mod v1_0_0 {
+                pub struct Config {
+                    port: u16,
+                }
+            }
+            pub mod v10_0_0 {
+                pub struct Config {}
+            }
+        }
+        .to_string()
+    );
+}
+
+#[test]
+fn test_expand_prefix_segments() {
+    let res: String = expand_to_string(
+        r#"v1_0_0, v10_0_0"#,
+        r#"
+            pub struct Config {
+                #[version("v1")]
+                port: u16,
+            }
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        res,
+        quote! {
+            pub mod v1_0_0 {
+                pub struct Config {
+                    port: u16,
+                }
+            }
+            pub mod v10_0_0 {
+                pub struct Config {}
+            }
+        }
+        .to_string()
+    );
+}
+
+#[test]
+fn test_expand_type_suffix_values() {
+    let err: String = expand_to_string(r#"v1_0_0, type_suffix = true"#, r#"pub const PORT: u16 = 80;"#).unwrap_err();
+    assert!(err.contains("`type_suffix` cannot be used on constant 'PORT'"), "{err}");
+    let err: String = expand_to_string(r#"v1_0_0, type_suffix = true"#, r#"pub fn port() -> u16 { 80 }"#).unwrap_err();
+    assert!(err.contains("`type_suffix` cannot be used on function 'port'"), "{err}");
+}
+
+#[test]
+fn test_filter_display_round_trip() {
+    // The serialization holds everything but the spans, so it's equal iff the filters are
+    for filter in FILTERS {
+        let filter: VersionFilter = syn::parse_str(filter).unwrap();
+        let res: VersionFilter = syn::parse_str(&filter.to_string()).unwrap();
+        assert_eq!(res.to_string(), filter.to_string());
+    }
+}
+
+#[test]
+fn test_filter_to_tokens_round_trip() {
+    for filter in FILTERS {
+        let filter: VersionFilter = syn::parse_str(filter).unwrap();
+        let res: VersionFilter = syn::parse2(filter.to_token_stream()).unwrap();
+        assert_eq!(res.to_string(), filter.to_string());
+    }
+}
+
+#[test]
+fn test_expand_repeated_flags() {
+    let res: String = expand_to_string(
+        r#"v1_0_0, v2_0_0, v3_0_0"#,
+        r#"
+            #[version_constness("v1_0_0")]
+            #[version_constness("v3_0_0")]
+            #[version_unsafe("v2_0_0")]
+            #[version_unsafe("v3_0_0")]
+            pub fn answer() -> u8 { 42 }
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        res,
+        quote! {
+            pub mod v1_0_0 {
+                pub const fn answer() -> u8 { 42 }
+            }
+            pub mod v2_0_0 {
+                pub unsafe fn answer() -> u8 { 42 }
+            }
+            pub mod v3_0_0 {
+                pub const unsafe fn answer() -> u8 { 42 }
+            }
+        }
+        .to_string()
+    );
+}
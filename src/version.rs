@@ -4,7 +4,7 @@
 //  Created:
 //    21 Nov 2023, 22:07:03
 //  Last edited:
-//    20 Dec 2023, 19:18:13
+//    14 Oct 2026, 10:46:45
 //  Auto updated?
 //    Yes
 //
@@ -13,11 +13,91 @@
 //!   versions.
 //
 
-use proc_macro2::Span;
+use std::fmt::{Display, Formatter, Result as FResult};
+
+use proc_macro2::{Literal, Span, TokenStream as TokenStream2};
 use proc_macro_error::{Diagnostic, Level};
+use quote::{quote, ToTokens};
 use syn::parse::{Parse, ParseBuffer, ParseStream};
 use syn::punctuated::Punctuated;
-use syn::{parenthesized, Ident, LitStr, Token};
+use syn::token::Paren;
+use syn::{parenthesized, Ident, Lit, LitInt, LitStr, Path, Token};
+
+
+/***** CONSTANTS *****/
+/// The names of all the operators (functions and constants) that may be used in a [`VersionFilter`].
+const OPERATORS: [&str; 16] =
+    ["min", "max", "mne", "mxe", "window", "since", "until", "added_in", "removed_in", "not", "any", "all", "none", "always", "never", "default"];
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Computes the edit (Levenshtein) distance between two strings.
+///
+/// # Arguments
+/// - `lhs`: The first string.
+/// - `rhs`: The second string.
+///
+/// # Returns
+/// The minimum number of single-character insertions, deletions or substitutions that turns `lhs` into `rhs`.
+pub(crate) fn edit_distance(lhs: &str, rhs: &str) -> usize {
+    let rhs: Vec<char> = rhs.chars().collect();
+    let mut prev: Vec<usize> = (0..=rhs.len()).collect();
+    for (i, l) in lhs.chars().enumerate() {
+        let mut row: Vec<usize> = Vec::with_capacity(rhs.len() + 1);
+        row.push(i + 1);
+        for (j, r) in rhs.iter().enumerate() {
+            row.push((prev[j] + usize::from(l != *r)).min(prev[j + 1] + 1).min(row[j] + 1));
+        }
+        prev = row;
+    }
+    prev[rhs.len()]
+}
+
+/// Computes the span of the tokens that have been parsed since some point.
+///
+/// # Arguments
+/// - `start`: A fork of the [`ParseStream`] taken at the first token to include.
+/// - `end`: The [`ParseStream`] after parsing the last token to include.
+///
+/// # Returns
+/// The [`Span`] from the first to the last token, or only that of the first if the compiler can't join them.
+fn span_between(start: &ParseBuffer, end: &ParseBuffer) -> Span {
+    let mut cursor = start.cursor();
+    let first: Span = cursor.span();
+    let mut last: Span = first;
+    while cursor != end.cursor() {
+        match cursor.token_tree() {
+            Some((token, next)) => {
+                last = token.span();
+                cursor = next;
+            },
+            None => break,
+        }
+    }
+    first.join(last).unwrap_or(first)
+}
+
+/// Checks whether a version (prefix) matches the start of the given version name.
+///
+/// Only whole `_`-separated segments are matched, e.g., `v1` is a prefix of `v1_0_0` but not of `v10_0_0`. The empty
+/// string is a prefix of every version.
+///
+/// # Arguments
+/// - `prefix`: The (prefix of a) version as given by the user.
+/// - `name`: The name of the version to match it to.
+///
+/// # Returns
+/// True if `prefix` is `name` or a prefix of it, or false otherwise.
+fn is_prefix_of(prefix: &str, name: &str) -> bool {
+    match name.strip_prefix(prefix) {
+        Some(rest) => prefix.is_empty() || prefix.ends_with('_') || rest.is_empty() || rest.starts_with('_'),
+        None => false,
+    }
+}
+
 
 
 /***** INTERFACE *****/
@@ -42,21 +122,56 @@ pub trait Filter {
 /// A version string that matches (part of) a filter.
 ///
 /// Implemented as a string that matches the prefix of the version string in question, e.g.,
+/// ```text
+/// "v1_0"
 /// ```
-/// "1.0" 
-/// ```
-/// matches all versions starting with `1.0`.
+/// matches all versions starting with `v1_0`. Only whole `_`-separated segments are matched, so it matches `v1_0_1` but
+/// not `v1_01_0`.
 #[derive(Clone, Debug)]
 pub struct Version(pub Ident);
 impl Parse for Version {
     fn parse(input: ParseStream) -> syn::Result<Self> { Ok(Self(input.parse()?)) }
 }
+impl ToTokens for Version {
+    #[inline]
+    fn to_tokens(&self, tokens: &mut TokenStream2) { self.0.to_tokens(tokens) }
+}
 
 /// A list of [`Version`]s that are defined.
 ///
 /// Used in the [`#[versioning(...)]`](crate::versioning())-macro to define the list (and order) of versions.
 #[derive(Clone, Debug)]
 pub struct VersionList(pub Vec<Version>);
+impl VersionList {
+    /// Resolves the position of a version in this list.
+    ///
+    /// Versions are matched exactly first. If there's no exact match, a version that is a unique prefix of a listed version
+    /// (e.g., `v1` if only `v1_0_0` starts with it) resolves to that version instead. Prefixes only match whole `_`-separated
+    /// segments (see [`is_prefix_of()`]), so `v1` is not a prefix of `v10_0_0`.
+    ///
+    /// # Arguments
+    /// - `name`: The name of the version to find.
+    ///
+    /// # Returns
+    /// The index of the version in this list, or [`None`] if it's unknown (or an ambiguous prefix).
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        if let Some(i) = self.0.iter().position(|v| v.0 == name) {
+            return Some(i);
+        }
+        let mut prefixed = self.0.iter().enumerate().filter(|(_, v)| is_prefix_of(name, &v.0.to_string()));
+        match (prefixed.next(), prefixed.next()) {
+            (Some((i, _)), None) => Some(i),
+            _ => None,
+        }
+    }
+}
+impl ToTokens for VersionList {
+    #[inline]
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let versions: &[Version] = &self.0;
+        tokens.extend(quote! { #(#versions),* })
+    }
+}
 impl Parse for VersionList {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         Ok(Self(input.call(|buf: &ParseBuffer| -> syn::Result<Vec<Version>> {
@@ -87,6 +202,19 @@ impl Parse for VersionList {
 pub enum VersionFilter {
     /// It's a version string.
     Version(LitStr),
+    /// It's `always`, which matches every version.
+    Always,
+    /// It's `never`, which matches no version.
+    Never,
+    /// It's `default`, which matches every version just like `always`.
+    ///
+    /// This is the explicit form of leaving out `#[version(...)]` altogether, and so satisfies `require_version`.
+    Default,
+    /// It's `else`, which matches the versions in which none of the siblings with the same name exist.
+    ///
+    /// It is resolved to `not(any(...))` of the siblings' filters before it's used, so on its own it matches no version (and
+    /// fails to [verify](VersionFilter::verify())).
+    Else(Span),
 
     /// It's a `>` (ordered by appearance in `#[versioning]`)
     AtLeastExcl(LitStr),
@@ -96,88 +224,336 @@ pub enum VersionFilter {
     AtMostExcl(LitStr),
     /// It's a `<=` (ordered by appearance in `#[versioning]`)
     AtMost(LitStr),
+    /// It's a `window(<version>, <n>)`, which matches the `n` versions starting at the given one (ordered by appearance in
+    /// `#[versioning]`).
+    ///
+    /// A window that runs past the last version is clamped to it, so it may be declared before all of its versions are.
+    Window(LitStr, usize),
 
-    /// It's a negation of a filter (i.e., anything _but_...)
-    Not(Box<Self>),
-    /// It's a disjunction between nested filters
-    Any(Vec<Self>),
-    /// It's a conjunction between nested filters
-    All(Vec<Self>),
+    /// It's a negation of a filter (i.e., anything _but_...), together with the span of the whole expression.
+    Not(Box<Self>, Span),
+    /// It's a disjunction between nested filters.
+    ///
+    /// Like in boolean algebra, an empty disjunction (i.e., `any()`) matches _no_ version. This is the dual of
+    /// [`VersionFilter::All`].
+    ///
+    /// The span is that of the whole expression.
+    Any(Vec<Self>, Span),
+    /// It's a conjunction between nested filters.
+    ///
+    /// Like in boolean algebra, an empty conjunction (i.e., `all()`) matches _every_ version. This is the dual of
+    /// [`VersionFilter::Any`].
+    ///
+    /// The span is that of the whole expression.
+    All(Vec<Self>, Span),
 }
 impl VersionFilter {
     /// Verifies if all versions are known, then emits errors if they aren't.
     ///
+    /// Like a plain version, the version of an ordered filter (e.g., `min(...)`) may be a prefix. Because it has to be
+    /// ordered, though, it must then resolve to exactly one version (see [`VersionList::index_of()`]); e.g., `min("v1")` is
+    /// `min("v1_0_0")` if that's the only version starting with `v1`.
+    ///
     /// # Arguments
     /// - `list`: A [`VersionList`] that determines known versions.
     ///
     /// # Errors
-    /// This function emits a [`Diagnostic`] if a [`Version`] in this filter did not exist.
+    /// This function emits a [`Diagnostic`] if a [`Version`] in this filter did not exist. It quotes the whole filter (as
+    /// serialized by its [`Display`]-implementation) and points at the innermost `not(...)`, `any(...)` or `all(...)` that
+    /// contains the unknown version. It also emits one if the version of an ordered filter is a prefix of multiple versions.
     pub fn verify(&self, list: &VersionList) -> Result<(), Diagnostic> {
-        // Match on the operation
-        let unknown_span: Option<(String, Span)> = match self {
-            Self::Version(ver) => {
-                if !list.0.iter().any(|v| v.0.to_string().starts_with(&ver.value())) {
-                    Some((ver.value(), ver.span()))
-                } else {
-                    None
-                }
-            },
+        // Any `else` that's left could not be resolved
+        if let Some(span) = self.find_else() {
+            return Err(Diagnostic::spanned(
+                span,
+                Level::Error,
+                "`else` can only be used by itself on an item, field or variant next to siblings with the same name (e.g., `#[version(else)] fn foo() {}`)".into(),
+            ));
+        }
 
-            Self::AtLeastExcl(ver) => {
-                if !list.0.iter().any(|v| v.0.to_string() == ver.value()) {
-                    Some((ver.value(), ver.span()))
-                } else {
-                    None
+        // If we found any unknown ones, emit the diagnostic
+        if let Some((ver, span, sub)) = self.find_unknown(list) {
+            let whole: String = self.to_string();
+            let context: String = if sub != whole { format!(" in `{sub}`") } else { String::new() };
+            return Err(Diagnostic::spanned(
+                span,
+                Level::Error,
+                format!("In filter `{whole}`: unknown version string '{ver}'{context} (add it to your `#[versioning(...)]` list of known versions)"),
+            ));
+        }
+
+        // Ordered filters must also know _which_ version they're ordering by
+        match self.find_ambiguous(list) {
+            Some((ver, span, candidates)) => Err(Diagnostic::spanned(
+                span,
+                Level::Error,
+                format!(
+                    "In filter `{self}`: version '{ver}' is ambiguous, as it's a prefix of {} (spell out the version to order by)",
+                    candidates.iter().map(|v| format!("'{v}'")).collect::<Vec<String>>().join(", ")
+                ),
+            )),
+            None => Ok(()),
+        }
+    }
+    /// Returns the span of this filter if it's a nested expression (i.e., `not(...)`, `any(...)` or `all(...)`).
+    ///
+    /// # Returns
+    /// The [`Span`] of the whole expression, or [`None`] if this isn't a nested expression.
+    #[inline]
+    fn group_span(&self) -> Option<Span> {
+        match self {
+            Self::Not(_, span) | Self::Any(_, span) | Self::All(_, span) => Some(*span),
+            _ => None,
+        }
+    }
+    /// Finds the first `else` in this filter.
+    ///
+    /// # Returns
+    /// The [`Span`] of the `else`, or [`None`] if there is none.
+    fn find_else(&self) -> Option<Span> {
+        match self {
+            Self::Else(span) => Some(*span),
+            Self::Not(filter, _) => filter.find_else(),
+            Self::Any(filters, _) | Self::All(filters, _) => filters.iter().find_map(Self::find_else),
+            _ => None,
+        }
+    }
+    /// Finds the first version of an ordered filter that is a prefix of multiple versions.
+    ///
+    /// # Arguments
+    /// - `list`: A [`VersionList`] that determines known versions.
+    ///
+    /// # Returns
+    /// A tuple with the ambiguous version, its span and the versions it's a prefix of, or [`None`] if there is none.
+    fn find_ambiguous(&self, list: &VersionList) -> Option<(String, Span, Vec<String>)> {
+        match self {
+            Self::AtLeastExcl(ver) | Self::AtLeast(ver) | Self::AtMostExcl(ver) | Self::AtMost(ver) | Self::Window(ver, _) => {
+                let value: String = ver.value();
+                if list.index_of(&value).is_none() {
+                    let candidates: Vec<String> = list.0.iter().map(|v| v.0.to_string()).filter(|v| is_prefix_of(&value, v)).collect();
+                    if candidates.len() > 1 {
+                        return Some((value, ver.span(), candidates));
+                    }
                 }
+                None
             },
-            Self::AtLeast(ver) => {
-                if !list.0.iter().any(|v| v.0.to_string() == ver.value()) {
-                    Some((ver.value(), ver.span()))
+            Self::Not(filter, _) => filter.find_ambiguous(list),
+            Self::Any(filters, _) | Self::All(filters, _) => filters.iter().find_map(|filter| filter.find_ambiguous(list)),
+            _ => None,
+        }
+    }
+    /// Finds the first version in this filter that is unknown.
+    ///
+    /// # Arguments
+    /// - `list`: A [`VersionList`] that determines known versions.
+    ///
+    /// # Returns
+    /// A tuple with the unknown version, the span of the (sub-)filter it occurred in and the serialized sub-filter, or
+    /// [`None`] if all of them are known.
+    fn find_unknown(&self, list: &VersionList) -> Option<(String, Span, String)> {
+        // Match on the operation
+        match self {
+            Self::Version(ver) => {
+                if !list.0.iter().any(|v| is_prefix_of(&ver.value(), &v.0.to_string())) {
+                    Some((ver.value(), ver.span(), self.to_string()))
                 } else {
                     None
                 }
             },
-            Self::AtMostExcl(ver) => {
-                if !list.0.iter().any(|v| v.0.to_string() == ver.value()) {
-                    Some((ver.value(), ver.span()))
+            Self::Always | Self::Never | Self::Default | Self::Else(_) => None,
+
+            Self::AtLeastExcl(ver) | Self::AtLeast(ver) | Self::AtMostExcl(ver) | Self::AtMost(ver) | Self::Window(ver, _) => {
+                if !list.0.iter().any(|v| is_prefix_of(&ver.value(), &v.0.to_string())) {
+                    Some((ver.value(), ver.span(), self.to_string()))
                 } else {
                     None
                 }
             },
-            Self::AtMost(ver) => {
-                if !list.0.iter().any(|v| v.0.to_string() == ver.value()) {
-                    Some((ver.value(), ver.span()))
+
+            Self::Not(filter, span) => filter.find_unknown(list).map(|(ver, sub_span, sub)| {
+                // Point at this expression if the version occurred directly in it
+                if filter.group_span().is_some() {
+                    (ver, sub_span, sub)
                 } else {
-                    None
+                    (ver, *span, self.to_string())
                 }
-            },
+            }),
+            Self::Any(filters, span) | Self::All(filters, span) => filters.iter().find_map(|filter| {
+                filter.find_unknown(list).map(|(ver, sub_span, sub)| {
+                    // Point at this expression if the version occurred directly in it
+                    if filter.group_span().is_some() {
+                        (ver, sub_span, sub)
+                    } else {
+                        (ver, *span, self.to_string())
+                    }
+                })
+            }),
+        }
+    }
+    /// Parses the `since = "..."` or `until = "..."` shorthand, of which the key has already been parsed.
+    ///
+    /// `since` lowers to `min(...)` and `until` to `mxe(...)`. Every shorthand is a filter on its own (e.g., in
+    /// `any(until = "v1_0_0", since = "v3_0_0")`), except that a `since` and an `until` next to each other at the toplevel of
+    /// an attribute form a single range (see [`VersionFilter::parse_trailing()`]).
+    ///
+    /// `added_in` and `removed_in` are the same as `since` and `until`, respectively, but read better when deprecating.
+    ///
+    /// # Arguments
+    /// - `key`: The key, i.e., `since`, `until`, `added_in` or `removed_in`.
+    /// - `input`: The [`ParseStream`] to parse the rest from, starting at the `=` after `key`.
+    ///
+    /// # Returns
+    /// The lowered [`VersionFilter`].
+    ///
+    /// # Errors
+    /// This function errors if the value was not a string literal.
+    fn parse_bounds(key: Ident, input: ParseStream) -> syn::Result<Self> {
+        input.parse::<Token![=]>()?;
+        let value: LitStr = Self::parse_operand(input)?;
+        if key == "until" || key == "removed_in" {
+            Ok(Self::AtMostExcl(value))
+        } else {
+            Ok(Self::AtLeast(value))
+        }
+    }
 
-            Self::Not(filter) => {
-                filter.verify(list)?;
+    /// Checks whether the input starts with a `since = "..."` or `until = "..."` shorthand.
+    ///
+    /// # Arguments
+    /// - `input`: The [`ParseStream`] to peek into.
+    ///
+    /// # Returns
+    /// [`Some(true)`] if it starts with `since` (or `added_in`), [`Some(false)`] if it starts with `until` (or `removed_in`), or
+    /// [`None`] if it starts with neither.
+    fn peek_bounds(input: ParseStream) -> Option<bool> {
+        if !input.peek2(Token![=]) {
+            return None;
+        }
+        match input.fork().parse::<Ident>() {
+            Ok(ident) if ident == "since" || ident == "added_in" => Some(true),
+            Ok(ident) if ident == "until" || ident == "removed_in" => Some(false),
+            _ => None,
+        }
+    }
+
+    /// Parses a filter that may be followed by a trailing comma, like the only argument of an attribute.
+    ///
+    /// As a special case, a `since = "..."` and `until = "..."` given next to each other (in either order) form a single
+    /// range, which lowers to `all(min(...), mxe(...))`.
+    ///
+    /// # Arguments
+    /// - `input`: The [`ParseStream`] to parse the filter from.
+    ///
+    /// # Returns
+    /// The parsed [`VersionFilter`].
+    ///
+    /// # Errors
+    /// This function errors if the filter failed to parse.
+    pub fn parse_trailing(input: ParseStream) -> syn::Result<Self> {
+        let start: Span = input.span();
+        let since: Option<bool> = Self::peek_bounds(input);
+        let mut filter: Self = input.parse()?;
+
+        // See if there's a complementary bound to go with it
+        if let (Some(since), Self::AtLeast(_) | Self::AtMostExcl(_)) = (since, &filter) {
+            let fork = input.fork();
+            if fork.parse::<Token![,]>().is_ok() && Self::peek_bounds(&fork) == Some(!since) {
+                input.parse::<Token![,]>()?;
+                input.parse::<Ident>()?;
+                input.parse::<Token![=]>()?;
+                let value: LitStr = Self::parse_operand(input)?;
+                let span: Span = start.join(value.span()).unwrap_or(start);
+                filter =
+                    if since { Self::All(vec![filter, Self::AtMostExcl(value)], span) } else { Self::All(vec![Self::AtLeast(value), filter], span) };
+            }
+        }
+        input.parse::<Option<Token![,]>>()?;
+        Ok(filter)
+    }
+
+    /// Parses a version given to an operator, which may be a string literal or a bare identifier.
+    ///
+    /// # Arguments
+    /// - `input`: The [`ParseStream`] to parse the version from.
+    ///
+    /// # Returns
+    /// The version as a [`LitStr`], which has the identifier's span if it was one.
+    ///
+    /// # Errors
+    /// This function errors if the input was neither a string literal nor an identifier.
+    fn parse_operand(input: ParseStream) -> syn::Result<LitStr> {
+        if input.peek(Ident) {
+            let ident: Ident = input.parse()?;
+            Ok(LitStr::new(&ident.to_string(), ident.span()))
+        } else {
+            input.parse()
+        }
+    }
+
+    /// Resolves the indices of the version in an ordered filter and the current version.
+    ///
+    /// # Arguments
+    /// - `list`: The [`VersionList`] that determines the version order.
+    /// - `ver`: The version in the filter.
+    /// - `version`: The current [`Version`].
+    ///
+    /// # Returns
+    /// A tuple with the index of `ver` and `version`, respectively, or [`None`] if either of them is unknown. In that case, a
+    /// [`Diagnostic`] is emitted (which fails the compilation) instead of panicking.
+    fn resolve_indices(list: &VersionList, ver: &LitStr, version: &Version) -> Option<(usize, usize)> {
+        let ver_i: Option<usize> = list.index_of(&ver.value());
+        let version_i: Option<usize> = list.index_of(&version.0.to_string());
+        match (ver_i, version_i) {
+            (Some(ver_i), Some(version_i)) => Some((ver_i, version_i)),
+            (None, _) => {
+                Diagnostic::spanned(ver.span(), Level::Error, format!("Cannot order unknown version '{}'", ver.value())).emit();
                 None
             },
-            Self::Any(vers) => {
-                for filter in vers {
-                    filter.verify(list)?;
-                }
+            (_, None) => {
+                Diagnostic::spanned(version.0.span(), Level::Error, format!("Cannot order unknown version '{}'", version.0)).emit();
                 None
             },
-            Self::All(vers) => {
-                for filter in vers {
-                    filter.verify(list)?;
+        }
+    }
+}
+impl Display for VersionFilter {
+    /// Formats the filter in the syntax that [`VersionFilter::parse()`] accepts.
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        /// Writes a comma-separated list of filters.
+        fn list(f: &mut Formatter<'_>, filters: &[VersionFilter]) -> FResult {
+            for (i, filter) in filters.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
                 }
-                None
-            },
-        };
+                write!(f, "{filter}")?;
+            }
+            Ok(())
+        }
 
-        // If we found any known ones, emit the diagnostic
-        match unknown_span {
-            Some((ver, span)) => Err(Diagnostic::spanned(
-                span,
-                Level::Error,
-                format!("Unknown version string '{ver}' (add it to your `#[versioning(...)]` list of known versions)"),
-            )),
-            None => Ok(()),
+        match self {
+            Self::Version(ver) => write!(f, "{:?}", ver.value()),
+            Self::Always => write!(f, "always"),
+            Self::Never => write!(f, "never"),
+            Self::Default => write!(f, "default"),
+            Self::Else(_) => write!(f, "else"),
+
+            Self::AtLeastExcl(ver) => write!(f, "mne({:?})", ver.value()),
+            Self::AtLeast(ver) => write!(f, "min({:?})", ver.value()),
+            Self::AtMostExcl(ver) => write!(f, "mxe({:?})", ver.value()),
+            Self::AtMost(ver) => write!(f, "max({:?})", ver.value()),
+            Self::Window(ver, n) => write!(f, "window({:?}, {n})", ver.value()),
+
+            Self::Not(filter, _) => write!(f, "not({filter})"),
+            Self::Any(filters, _) => {
+                write!(f, "any(")?;
+                list(f, filters)?;
+                write!(f, ")")
+            },
+            Self::All(filters, _) => {
+                write!(f, "all(")?;
+                list(f, filters)?;
+                write!(f, ")")
+            },
         }
     }
 }
@@ -186,77 +562,42 @@ impl Filter for VersionFilter {
     fn matches(&self, list: &VersionList, version: &Version) -> bool {
         // Match on the operation
         match self {
-            Self::Version(ver) => version.0.to_string().starts_with(&ver.value()),
+            Self::Version(ver) => is_prefix_of(&ver.value(), &version.0.to_string()),
+            Self::Always | Self::Default => true,
+            Self::Never | Self::Else(_) => false,
 
             Self::AtLeastExcl(ver) => {
-                // Find the index of both versions
-                let ver_i: usize =
-                    list.0.iter().position(|v| ver.value() == v.0.to_string()).unwrap_or_else(|| panic!("Encountered unknown version '{ver:?}'"));
-                let version_i: usize = list
-                    .0
-                    .iter()
-                    .position(|v| version.0.to_string() == v.0.to_string())
-                    .unwrap_or_else(|| panic!("Encountered unknown version '{version:?}'"));
-
-                // Compare
-                version_i > ver_i
+                // Find the index of both versions, then compare
+                Self::resolve_indices(list, ver, version).map(|(ver_i, version_i)| version_i > ver_i).unwrap_or(false)
             },
             Self::AtLeast(ver) => {
-                // Find the index of both versions
-                let ver_i: usize =
-                    list.0.iter().position(|v| ver.value() == v.0.to_string()).unwrap_or_else(|| panic!("Encountered unknown version '{ver:?}'"));
-                let version_i: usize = list
-                    .0
-                    .iter()
-                    .position(|v| version.0.to_string() == v.0.to_string())
-                    .unwrap_or_else(|| panic!("Encountered unknown version '{version:?}'"));
-
-                // Compare
-                version_i >= ver_i
+                // Find the index of both versions, then compare
+                Self::resolve_indices(list, ver, version).map(|(ver_i, version_i)| version_i >= ver_i).unwrap_or(false)
             },
             Self::AtMostExcl(ver) => {
-                // Find the index of both versions
-                let ver_i: usize =
-                    list.0.iter().position(|v| ver.value() == v.0.to_string()).unwrap_or_else(|| panic!("Encountered unknown version '{ver:?}'"));
-                let version_i: usize = list
-                    .0
-                    .iter()
-                    .position(|v| version.0.to_string() == v.0.to_string())
-                    .unwrap_or_else(|| panic!("Encountered unknown version '{version:?}'"));
-
-                // Compare
-                version_i < ver_i
+                // Find the index of both versions, then compare
+                Self::resolve_indices(list, ver, version).map(|(ver_i, version_i)| version_i < ver_i).unwrap_or(false)
             },
             Self::AtMost(ver) => {
-                // Find the index of both versions
-                let ver_i: usize =
-                    list.0.iter().position(|v| ver.value() == v.0.to_string()).unwrap_or_else(|| panic!("Encountered unknown version '{ver:?}'"));
-                let version_i: usize = list
-                    .0
-                    .iter()
-                    .position(|v| version.0.to_string() == v.0.to_string())
-                    .unwrap_or_else(|| panic!("Encountered unknown version '{version:?}'"));
-
-                // Compare
-                version_i <= ver_i
+                // Find the index of both versions, then compare
+                Self::resolve_indices(list, ver, version).map(|(ver_i, version_i)| version_i <= ver_i).unwrap_or(false)
+            },
+            Self::Window(ver, n) => {
+                // Find the index of both versions, then check if it's in the window
+                Self::resolve_indices(list, ver, version).map(|(ver_i, version_i)| version_i >= ver_i && version_i - ver_i < *n).unwrap_or(false)
             },
 
-            Self::Not(filter) => !filter.matches(list, version),
-            Self::Any(vers) => {
-                // Only one needs to match
+            Self::Not(filter, _) => !filter.matches(list, version),
+            Self::Any(vers, _) => {
+                // Only one needs to match (which never holds for empty lists)
                 let mut res: bool = false;
                 for filter in vers {
                     res = res || filter.matches(list, version);
                 }
                 res
             },
-            Self::All(vers) => {
-                // Catch empty lists
-                if vers.is_empty() {
-                    return false;
-                }
-
-                // Otherwise, require all to match
+            Self::All(vers, _) => {
+                // Require all to match (which trivially holds for empty lists)
                 let mut res: bool = true;
                 for filter in vers {
                     res = res && filter.matches(list, version);
@@ -266,62 +607,260 @@ impl Filter for VersionFilter {
         }
     }
 }
-impl Parse for VersionFilter {
-    fn parse(input: ParseStream) -> syn::Result<Self> {
+impl ToTokens for VersionFilter {
+    /// Serializes the filter in the syntax that [`VersionFilter::parse()`] accepts.
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        tokens.extend(match self {
+            Self::Version(ver) => quote! { #ver },
+            Self::Always => quote! { always },
+            Self::Never => quote! { never },
+            Self::Default => quote! { default },
+            Self::Else(_) => quote! { else },
+
+            Self::AtLeastExcl(ver) => quote! { mne(#ver) },
+            Self::AtLeast(ver) => quote! { min(#ver) },
+            Self::AtMostExcl(ver) => quote! { mxe(#ver) },
+            Self::AtMost(ver) => quote! { max(#ver) },
+            Self::Window(ver, n) => {
+                let n: Literal = Literal::usize_unsuffixed(*n);
+                quote! { window(#ver, #n) }
+            },
+
+            Self::Not(filter, _) => quote! { not(#filter) },
+            Self::Any(filters, _) => quote! { any(#(#filters),*) },
+            Self::All(filters, _) => quote! { all(#(#filters),*) },
+        })
+    }
+}
+impl VersionFilter {
+    /// Parses a disjunction of the infix form, i.e., `<filter> || <filter> || ...`.
+    ///
+    /// `||` binds the weakest, so its operands are parsed as conjunctions.
+    ///
+    /// # Arguments
+    /// - `input`: The [`ParseStream`] to parse from.
+    ///
+    /// # Returns
+    /// The parsed [`VersionFilter`], which is a [`VersionFilter::Any`] if there was at least one `||`.
+    ///
+    /// # Errors
+    /// This function errors if any of the operands failed to parse.
+    fn parse_or(input: ParseStream) -> syn::Result<Self> {
+        let start: ParseBuffer = input.fork();
+        let first: Self = Self::parse_and(input)?;
+        if !input.peek(Token![||]) {
+            return Ok(first);
+        }
+        let mut filters: Vec<Self> = vec![first];
+        while input.peek(Token![||]) {
+            input.parse::<Token![||]>()?;
+            filters.push(Self::parse_and(input)?);
+        }
+        let span: Span = span_between(&start, input);
+        Ok(Self::Any(filters, span))
+    }
+
+    /// Parses a conjunction of the infix form, i.e., `<filter> && <filter> && ...`.
+    ///
+    /// `&&` binds stronger than `||` but weaker than `!`, so its operands are parsed as negations.
+    ///
+    /// # Arguments
+    /// - `input`: The [`ParseStream`] to parse from.
+    ///
+    /// # Returns
+    /// The parsed [`VersionFilter`], which is a [`VersionFilter::All`] if there was at least one `&&`.
+    ///
+    /// # Errors
+    /// This function errors if any of the operands failed to parse.
+    fn parse_and(input: ParseStream) -> syn::Result<Self> {
+        let start: ParseBuffer = input.fork();
+        let first: Self = Self::parse_not(input)?;
+        if !input.peek(Token![&&]) {
+            return Ok(first);
+        }
+        let mut filters: Vec<Self> = vec![first];
+        while input.peek(Token![&&]) {
+            input.parse::<Token![&&]>()?;
+            filters.push(Self::parse_not(input)?);
+        }
+        let span: Span = span_between(&start, input);
+        Ok(Self::All(filters, span))
+    }
+
+    /// Parses a negation of the infix form (i.e., `!<filter>`), a parenthesized group (i.e., `(<filter>)`) or a single
+    /// operand.
+    ///
+    /// # Arguments
+    /// - `input`: The [`ParseStream`] to parse from.
+    ///
+    /// # Returns
+    /// The parsed [`VersionFilter`].
+    ///
+    /// # Errors
+    /// This function errors if the negated filter, group or operand failed to parse.
+    fn parse_not(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![!]) {
+            let start: ParseBuffer = input.fork();
+            input.parse::<Token![!]>()?;
+            let filter: Self = Self::parse_not(input)?;
+            Ok(Self::Not(Box::new(filter), span_between(&start, input)))
+        } else if input.peek(Paren) {
+            // Recurse into a full filter for the group
+            let contents;
+            parenthesized!(contents in input);
+            Self::parse_or(&contents)
+        } else {
+            Self::parse_operand_filter(input)
+        }
+    }
+
+    /// Parses a single operand of the infix form, i.e., a version or an operator function.
+    ///
+    /// # Arguments
+    /// - `input`: The [`ParseStream`] to parse from.
+    ///
+    /// # Returns
+    /// The parsed [`VersionFilter`].
+    ///
+    /// # Errors
+    /// This function errors if the input was not a version or a known operator function.
+    fn parse_operand_filter(input: ParseStream) -> syn::Result<Self> {
         // We can use a lookahead here
         let lookahead = input.lookahead1();
         if lookahead.peek(LitStr) {
             Ok(Self::Version(input.parse()?))
+        } else if lookahead.peek(Token![else]) {
+            let token: Token![else] = input.parse()?;
+            Ok(Self::Else(token.span))
+        } else if (lookahead.peek(Ident) && input.peek2(Token![::])) || input.peek(Token![::]) {
+            // Operators are never namespaced, so this is likely a path to something else entirely
+            let path: Path = input.parse()?;
+            Err(syn::Error::new_spanned(
+                &path,
+                format!(
+                    "Expected a bare operator name, not a path like `{}` (e.g., `all(...)`, `not(...)`, etc)",
+                    path.to_token_stream().to_string().replace(" ", "")
+                ),
+            ))
         } else if lookahead.peek(Ident) {
             // Check _which_ identifier
             let ident: Ident = input.parse()?;
-            if ident == "mne" {
+            if (ident == "since" || ident == "until" || ident == "added_in" || ident == "removed_in") && input.peek(Token![=]) {
+                Self::parse_bounds(ident, input)
+            } else if ident == "always" {
+                Ok(Self::Always)
+            } else if ident == "never" {
+                Ok(Self::Never)
+            } else if ident == "default" {
+                Ok(Self::Default)
+            } else if ident == "mne" {
                 // Parse brackets, with a new version filter in between them
                 let contents;
                 parenthesized!(contents in input);
-                let version: LitStr = contents.parse()?;
+                let version: LitStr = Self::parse_operand(&contents)?;
+                contents.parse::<Option<Token![,]>>()?;
                 Ok(Self::AtLeastExcl(version))
             } else if ident == "min" {
                 // Parse brackets, with a new version filter in between them
                 let contents;
                 parenthesized!(contents in input);
-                let version: LitStr = contents.parse()?;
+                let version: LitStr = Self::parse_operand(&contents)?;
+                contents.parse::<Option<Token![,]>>()?;
                 Ok(Self::AtLeast(version))
             } else if ident == "mxe" {
                 // Parse brackets, with a new version filter in between them
                 let contents;
                 parenthesized!(contents in input);
-                let version: LitStr = contents.parse()?;
+                let version: LitStr = Self::parse_operand(&contents)?;
+                contents.parse::<Option<Token![,]>>()?;
                 Ok(Self::AtMostExcl(version))
             } else if ident == "max" {
                 // Parse brackets, with a new version filter in between them
                 let contents;
                 parenthesized!(contents in input);
-                let version: LitStr = contents.parse()?;
+                let version: LitStr = Self::parse_operand(&contents)?;
+                contents.parse::<Option<Token![,]>>()?;
                 Ok(Self::AtMost(version))
+            } else if ident == "window" {
+                // Parse brackets, with a version and the size of the window in between them
+                let contents;
+                parenthesized!(contents in input);
+                let version: LitStr = Self::parse_operand(&contents)?;
+                contents.parse::<Token![,]>()?;
+                let n: LitInt = contents.parse()?;
+                contents.parse::<Option<Token![,]>>()?;
+                match n.base10_parse::<usize>()? {
+                    0 => Err(syn::Error::new(n.span(), "Window must contain at least one version")),
+                    n => Ok(Self::Window(version, n)),
+                }
             } else if ident == "not" {
                 // Parse brackets, with a new version filter in between them
                 let contents;
-                parenthesized!(contents in input);
+                let paren = parenthesized!(contents in input);
+                let span: Span = ident.span().join(paren.span.join()).unwrap_or_else(|| paren.span.join());
                 let filter: VersionFilter = contents.parse()?;
-                Ok(Self::Not(Box::new(filter)))
+                contents.parse::<Option<Token![,]>>()?;
+                Ok(Self::Not(Box::new(filter), span))
             } else if ident == "any" {
                 // Parse brackets, with any number of version filters tokens in between them
                 let contents;
-                parenthesized!(contents in input);
+                let paren = parenthesized!(contents in input);
+                let span: Span = ident.span().join(paren.span.join()).unwrap_or_else(|| paren.span.join());
                 let filters: Punctuated<VersionFilter, Token![,]> = contents.parse_terminated(VersionFilter::parse, Token![,])?;
-                Ok(Self::Any(filters.into_iter().collect()))
+                Ok(Self::Any(filters.into_iter().collect(), span))
             } else if ident == "all" {
                 // Parse brackets, with any number of version filters tokens in between them
                 let contents;
-                parenthesized!(contents in input);
+                let paren = parenthesized!(contents in input);
+                let span: Span = ident.span().join(paren.span.join()).unwrap_or_else(|| paren.span.join());
                 let filters: Punctuated<VersionFilter, Token![,]> = contents.parse_terminated(VersionFilter::parse, Token![,])?;
-                Ok(Self::All(filters.into_iter().collect()))
+                Ok(Self::All(filters.into_iter().collect(), span))
+            } else if ident == "none" {
+                // Parse brackets, with any number of version filters tokens in between them, which is sugar for `not(any(...))`
+                let contents;
+                let paren = parenthesized!(contents in input);
+                let span: Span = ident.span().join(paren.span.join()).unwrap_or_else(|| paren.span.join());
+                let filters: Punctuated<VersionFilter, Token![,]> = contents.parse_terminated(VersionFilter::parse, Token![,])?;
+                Ok(Self::Not(Box::new(Self::Any(filters.into_iter().collect(), span)), span))
+            } else if !input.peek(Paren) {
+                // It's not a function, so take it as a bare version instead (like in `#[versioning(...)]`)
+                Ok(Self::Version(LitStr::new(&ident.to_string(), ident.span())))
             } else {
-                Err(input.error(format!("Unknown operator function '{ident}' (expected `not`, `any` or `all`)")))
+                // Suggest the closest one, if it's close enough to be a typo (or it's spelled out, e.g., `minimum`)
+                let name: String = ident.to_string();
+                let closest: Option<&str> = OPERATORS
+                    .iter()
+                    .map(|op| (if name.starts_with(op) { 0 } else { edit_distance(&name, op) }, *op))
+                    .filter(|(dist, _)| *dist <= name.len().max(3) / 2)
+                    .min()
+                    .map(|(_, op)| op);
+                let ops: String = OPERATORS.iter().map(|op| format!("`{op}`")).collect::<Vec<String>>().join(", ");
+                match closest {
+                    Some(op) => Err(syn::Error::new(
+                        ident.span(),
+                        format!("Unknown operator function '{ident}' (did you mean `{op}`? Expected one of {ops})"),
+                    )),
+                    None => Err(syn::Error::new(ident.span(), format!("Unknown operator function '{ident}' (expected one of {ops})"))),
+                }
             }
+        } else if input.peek(Lit) {
+            // Versions that look like numbers still need quoting
+            let lit: Lit = input.parse()?;
+            Err(syn::Error::new(
+                lit.span(),
+                format!("Version must be a string literal or identifier (e.g., `\"v1_0_0\"` or `v1_0_0`), not `{}`", lit.to_token_stream()),
+            ))
         } else {
             Err(input.error("Expected string or operator function (e.g., `all(...)`, `not(...)`, etc)"))
         }
     }
 }
+impl Parse for VersionFilter {
+    /// Parses a filter, which is either written with operator functions (e.g., `all(...)`) or in the infix form (e.g.,
+    /// `"v1_0_0" || !"v2_0_0"`), or a mix of both.
+    ///
+    /// In the infix form, `!` binds the strongest, then `&&` and then `||`; parentheses may be used to group. These lower to
+    /// `not(...)`, `all(...)` and `any(...)`, respectively.
+    #[inline]
+    fn parse(input: ParseStream) -> syn::Result<Self> { Self::parse_or(input) }
+}
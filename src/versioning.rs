@@ -4,7 +4,7 @@
 //  Created:
 //    19 Nov 2023, 19:25:25
 //  Last edited:
-//    23 Dec 2023, 16:03:00
+//    14 Oct 2026, 10:50:42
 //  Auto updated?
 //    Yes
 //
@@ -13,40 +13,443 @@
 //
 
 use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet, VecDeque};
 
-use proc_macro2::{Span, TokenStream as TokenStream2};
+use proc_macro2::{Group, Span, TokenStream as TokenStream2, TokenTree};
 use proc_macro_error::{Diagnostic, Level};
-use quote::quote;
+use quote::{format_ident, quote, quote_spanned, ToTokens as _};
+use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::token::{Comma, Pub};
+use syn::visit_mut::VisitMut;
 use syn::{
-    Attribute, Expr, ExprLit, Field, Fields, FieldsNamed, FieldsUnnamed, ForeignItem, ForeignItemFn, ForeignItemMacro, ForeignItemStatic,
-    ForeignItemType, Ident, ImplItem, ImplItemConst, ImplItemFn, ImplItemMacro, ImplItemType, Item, ItemConst, ItemEnum, ItemExternCrate, ItemFn,
-    ItemForeignMod, ItemImpl, ItemMacro, ItemMod, ItemStatic, ItemStruct, ItemTrait, ItemTraitAlias, ItemType, ItemUnion, ItemUse, Lit, LitBool,
-    Meta, TraitItem, TraitItemConst, TraitItemFn, TraitItemMacro, TraitItemType, Variant, Visibility, WhereClause,
+    Attribute, Block, Expr, ExprLit, Field, Fields, FieldsNamed, FieldsUnnamed, ForeignItem, ForeignItemFn, ForeignItemMacro, ForeignItemStatic,
+    ForeignItemType, GenericParam, Generics, Ident, ImplItem, ImplItemConst, ImplItemFn, ImplItemMacro, ImplItemType, Item, ItemConst, ItemEnum,
+    ItemExternCrate, ItemFn, ItemForeignMod, ItemImpl, ItemMacro, ItemMod, ItemStatic, ItemStruct, ItemTrait, ItemTraitAlias, ItemType, ItemUnion,
+    ItemUse, Lit, LitBool, LitStr, Meta, Path, RangeLimits, Signature, Stmt, Token, TraitItem, TraitItemConst, TraitItemFn, TraitItemMacro,
+    TraitItemType, Type, TypeParamBound, TypePath, UseGroup, UseName, UsePath, UseRename, UseTree, Variant, VisRestricted, Visibility, WhereClause,
+    WherePredicate,
 };
 
 // use crate::spec::BodyItem;
 use crate::version::{Filter as _, Version, VersionFilter, VersionList};
 
 
+/***** GLOBALS *****/
+thread_local! {
+    /// Lists the names of the constants, statics and functions that are filtered out of the modules currently being generated.
+    ///
+    /// This allows us to catch references to them (e.g., in discriminants) that would dangle in the generated code.
+    static FILTERED_OUT: RefCell<Vec<Ident>> = const { RefCell::new(Vec::new()) };
+
+    /// Collects the things of which the `#[version(...)]`-attribute matches none of the versions, keyed by the address of their
+    /// attributes, so [`call()`] can warn about them once.
+    static DEAD: RefCell<Vec<(*const Attribute, Span, String)>> = const { RefCell::new(Vec::new()) };
+}
+
+
+
+
+
+/***** CONSTANTS *****/
+/// The names of all attributes that are consumed by the `#[versioning(...)]`-macro, and which are thus never re-emitted.
+const VERSION_ATTRS: &[&str] = &[
+    "version",
+    "version_attr",
+    "version_bound",
+    "version_convert",
+    "version_constness",
+    "version_default_value",
+    "version_derive",
+    "version_discriminant",
+    "version_doc",
+    "version_extern_rename",
+    "version_feature",
+    "version_impl_trait",
+    "version_module_name",
+    "version_no_derive",
+    "version_provided",
+    "version_rename",
+    "version_size",
+    "version_supertrait",
+    "version_type",
+    "version_unsafe",
+    "version_use",
+    "version_use_item",
+    "version_value",
+];
+
+/// The maximum depth of nested modules in a versioned item, which matches the default `recursion_limit` of the compiler.
+///
+/// Items are generated recursively, so this avoids overflowing the stack on (machine-generated) inputs that nest very deeply.
+const MAX_MODULE_DEPTH: usize = 128;
+
+
+
+
+
 /***** HELPERS *****/
 /// Defines the configurable options to the `#[versioning(...)]`-macro.
 #[derive(Debug)]
 struct Options {
     /// Whether to inject `#[cfg(feature = "...")]` when generating code or not.
-    features: bool,
+    features:              bool,
     /// Whether toplevel modules are wrapped or renamed.
     nest_toplevel_modules: bool,
+    /// If given, injects `#[non_exhaustive]` on generated structs and enums in this version and all later ones.
+    non_exhaustive_from:   Option<LitStr>,
+    /// Whether to generate a `...Builder` for every struct with named fields.
+    ///
+    /// Note that fields named `new` or `build` are rejected, as they would collide with the builder's own methods.
+    builder:               bool,
+    /// Whether to generate a [`Default`]-implementation for every struct, using `#[version_default_value(...)]` to find per-version values.
+    default:               bool,
+    /// Whether to generate a constant describing in which versions the fields of every struct exist.
+    field_matrix:          bool,
+    /// Whether to generate a constant listing which fields of every struct are added and removed between consecutive versions.
+    field_diff:            bool,
+    /// Whether to generate a constant listing the names of the fields of every struct in every version.
+    field_names_const:     bool,
+    /// Whether to inject `#[serde(default)]` on named fields that don't exist in all earlier versions, so older payloads can still
+    /// be deserialized into newer versions.
+    ///
+    /// Note that this only injects the attribute; deriving `serde::Deserialize` is still up to the user.
+    serde_defaults:        bool,
+    /// Whether to inject `#[derive(schemars::JsonSchema)]` on every generated struct and enum. Requires the `schemars` feature.
+    schemars:              bool,
+    /// Whether to generate a `schema()`-function in every version module that returns the JSON Schema of the toplevel struct or
+    /// enum. Requires the `schemars` feature, and assumes `schemars` 0.8.
+    schema_fn:             bool,
+    /// Whether to assert the size of every struct and enum with a `#[version_size(<filter>, <size>)]` at compile time.
+    ///
+    /// This catches accidental layout changes in versions that are supposed to be frozen. Items that are generic over types
+    /// or constants can't be asserted, as their size depends on what they're instantiated with.
+    size_asserts:          bool,
+    /// Whether every (nested) item, field and variant must have a `#[version(...)]`-attribute, instead of being in all versions
+    /// if it doesn't.
+    ///
+    /// Note that the toplevel item and the items in traits, impls and extern blocks are exempt. Things that should be in all
+    /// versions can say so with `#[version(default)]`.
+    require_version:       bool,
+    /// Any attributes to apply to every generated version module as a whole.
+    pass_attrs:            Vec<Meta>,
+    /// Whether to merge consecutive versions with identical code into one module.
+    ///
+    /// The module is generated for the first version of every run, and the others are aliased to it with a `use`. When using
+    /// `features` (or `cfg_mode`), the module is gated by any of their features instead.
+    ///
+    /// Versions that differ in nothing but attributes (e.g., a `#[version_derive(...)]`) are never merged, as a `use` cannot
+    /// change the attributes of the items it re-exports. This includes docs (e.g., from a `#[version_doc(...)]`), as rustdoc
+    /// would show the docs of a re-export in addition to those of the original.
+    coalesce:              bool,
+    /// If given, a template for the `#[cfg(...)]`-attribute to gate every version with instead of `feature = "..."`.
+    cfg_mode:              Option<LitStr>,
+    /// Whether to lowercase the version identifiers when using them as module names.
+    ///
+    /// Note that this only affects the generated modules; filters still match the versions as spelled in the list.
+    normalize_module_case: bool,
+    /// Whether to inject `#[doc(alias = "...")]` with the original name on items renamed by `#[version_rename(...)]`.
+    doc_alias_renames:     bool,
+    /// Whether to generate a unit struct for structs of which all fields are filtered out in a version.
+    ///
+    /// Structs that are declared with empty braces or parenthesis are always kept as-is.
+    unit_structs:          bool,
+    /// Whether to turn any warnings emitted by the macro into errors.
+    deny_warnings:         bool,
+    /// Whether to promote the visibility of toplevel items to `pub` when wrapping them in a version module.
+    force_public:          bool,
+    /// Whether to generate a `#[cfg(test)]`-module with some basic tests for every struct (and for its conversions, if any).
+    ///
+    /// The conversion tests only start from the [`Default`] value, and so only catch dropped fields of which the default is
+    /// set with a `#[version_default_value(...)]`.
+    gen_tests:             bool,
+    /// The order in which the version modules are emitted.
+    output_order:          OutputOrder,
+    /// Whether to suffix the name of the toplevel item with its version (e.g., `v1_0_0::ConfigV1_0_0`). Only supported for
+    /// type-like items (structs, enums, unions, traits, trait aliases and type aliases); others (e.g., modules, constants or
+    /// functions) are rejected.
+    type_suffix:           bool,
+    /// The names of the modules of versions that are overridden by `#[version_module_name(...)]` on the toplevel item.
+    module_names:          HashMap<String, Ident>,
+    /// The filters of the `#[version(...)]`-attributes in the item, as parsed by the [`FilterCollector`].
+    filters:               HashMap<Attribute, VersionFilter>,
+    /// Whether to generate [`From`]-implementations between consecutive versions (in both directions) of every struct with named fields.
+    ///
+    /// Fields are copied over or defaulted, unless a `#[version_convert(<filter>, <closure>)]` matches the version converted
+    /// into. Then the closure is called with a reference to the whole struct converted from to compute the field, regardless
+    /// of which version that is.
+    conversions:           bool,
+    /// If given, generates [`From`]-implementations between these pairs of versions too.
+    ///
+    /// Note that `"all"` generates one for every ordered pair, i.e., `n * (n - 1)` per struct for `n` versions (and more when
+    /// composing them), which quickly adds up in compile time. If only a few are needed, list them (e.g.,
+    /// `"v1_0_0 -> v3_0_0"`) instead.
+    conversion_pairs:      Option<ConversionPairs>,
+    /// Whether to compose conversions through intermediate versions for pairs of which some fields differ in type, instead of
+    /// converting those fields with [`Into`].
+    compose_conversions:   bool,
 }
 impl Default for Options {
     #[inline]
-    fn default() -> Self { Self { features: false, nest_toplevel_modules: false } }
+    fn default() -> Self {
+        Self {
+            features:              false,
+            nest_toplevel_modules: false,
+            non_exhaustive_from:   None,
+            builder:               false,
+            default:               false,
+            field_matrix:          false,
+            field_diff:            false,
+            field_names_const:     false,
+            serde_defaults:        false,
+            schemars:              false,
+            schema_fn:             false,
+            size_asserts:          false,
+            require_version:       false,
+            pass_attrs:            vec![],
+            coalesce:              false,
+            cfg_mode:              None,
+            normalize_module_case: false,
+            doc_alias_renames:     false,
+            unit_structs:          false,
+            deny_warnings:         false,
+            force_public:          true,
+            gen_tests:             false,
+            output_order:          OutputOrder::Declaration,
+            type_suffix:           false,
+            module_names:          HashMap::new(),
+            filters:               HashMap::new(),
+            conversions:           false,
+            conversion_pairs:      None,
+            compose_conversions:   false,
+        }
+    }
+}
+impl Options {
+    /// Checks whether generated structs and enums should be marked as `#[non_exhaustive]` for the given version.
+    ///
+    /// # Arguments
+    /// - `versions`: The list of versions in total (allows us to define order)
+    /// - `version`: The current version to check for.
+    ///
+    /// # Returns
+    /// True if the `version` sorts at or after the `non_exhaustive_from` cutoff, or false otherwise (or if there is no cutoff).
+    #[inline]
+    fn is_non_exhaustive(&self, versions: &VersionList, version: &Version) -> bool {
+        match &self.non_exhaustive_from {
+            Some(cutoff) => VersionFilter::AtLeast(cutoff.clone()).matches(versions, version),
+            None => false,
+        }
+    }
+
+    /// Returns the identifier of the module generated for the given version.
+    ///
+    /// # Arguments
+    /// - `version`: The [`Version`] to generate the module identifier of.
+    ///
+    /// # Returns
+    /// The name given by `#[version_module_name(...)]` if any, or else the version's identifier (lowercased if
+    /// `normalize_module_case` is given).
+    fn module_ident(&self, version: &Version) -> Ident {
+        if let Some(name) = self.module_names.get(&version.0.to_string()) {
+            name.clone()
+        } else if self.normalize_module_case {
+            let name: String = version.0.to_string();
+            match name.strip_prefix("r#") {
+                Some(name) => Ident::new_raw(&name.to_lowercase(), version.0.span()),
+                None => Ident::new(&name.to_lowercase(), version.0.span()),
+            }
+        } else {
+            version.0.clone()
+        }
+    }
+
+    /// Emits a warning, or an error if `deny_warnings` is given.
+    ///
+    /// Note that warnings are only shown when compiling with a nightly compiler.
+    ///
+    /// # Arguments
+    /// - `span`: The [`Span`] to point the warning at.
+    /// - `msg`: The message of the warning.
+    ///
+    /// # Errors
+    /// This function errors with the warning if `deny_warnings` is given.
+    fn warn(&self, span: Span, msg: String) -> Result<(), Diagnostic> {
+        if self.deny_warnings {
+            Err(Diagnostic::spanned(span, Level::Error, msg))
+        } else {
+            Diagnostic::spanned(span, Level::Warning, msg).emit();
+            Ok(())
+        }
+    }
+
+    /// Errors if `require_version` is given, for something that lacks a `#[version(...)]`-attribute.
+    ///
+    /// # Arguments
+    /// - `span`: The [`Span`] of the thing without the attribute.
+    /// - `what`: A description of the thing without the attribute (e.g., `Field 'foo'`).
+    ///
+    /// # Errors
+    /// This function errors if `require_version` is given.
+    fn require_version(&self, span: Span, what: String) -> Result<(), Diagnostic> {
+        if self.require_version {
+            Err(Diagnostic::spanned(
+                span,
+                Level::Error,
+                format!(
+                    "{what} has no `#[version(...)]`, which is required by `require_version` (use `#[version(default)]` to keep it in all versions)"
+                ),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Checks whether generated versions are gated behind a `#[cfg(...)]`.
+    ///
+    /// # Returns
+    /// True if either the `features` or `cfg_mode` option was given, or false otherwise.
+    #[inline]
+    fn is_gated(&self) -> bool { self.features || self.cfg_mode.is_some() }
+
+    /// Generates the predicate of the `#[cfg(...)]`-attribute that gates the given version.
+    ///
+    /// # Arguments
+    /// - `version`: The [`Version`] to generate the predicate for.
+    ///
+    /// # Returns
+    /// The predicate (i.e., the part in between the brackets of `#[cfg(...)]`) if the versions are gated, or [`None`] otherwise.
+    ///
+    /// # Errors
+    /// This function errors if the `cfg_mode` template did not result in a valid `cfg(...)`.
+    fn version_cfg(&self, version: &Version) -> Result<Option<TokenStream2>, Diagnostic> {
+        if let Some(template) = &self.cfg_mode {
+            // Substitute the version in the template, then parse it as a `cfg(...)`
+            let cfg: String = template.value().replace("{version}", &version.0.to_string());
+            match syn::parse_str::<Meta>(&cfg) {
+                Ok(Meta::List(l)) if l.path.is_ident("cfg") => Ok(Some(l.tokens)),
+                Ok(_) => Err(Diagnostic::spanned(
+                    template.span(),
+                    Level::Error,
+                    format!("'cfg_mode' option must be a template for a `cfg(...)`-attribute (got `{cfg}`)"),
+                )),
+                Err(err) => Err(Diagnostic::spanned(template.span(), Level::Error, format!("Failed to parse 'cfg_mode' template `{cfg}`: {err}"))),
+            }
+        } else if self.features {
+            let feature: String = version.0.to_string();
+            Ok(Some(quote! { feature = #feature }))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+
+/// Defines a single argument to the `#[versioning(...)]`-macro.
+#[derive(Debug)]
+enum InputArg {
+    /// It's a version range, e.g., `v1_0_0..=v1_0_5`.
+    Range(Ident, RangeLimits, Ident),
+    /// It's a version or an option.
+    Meta(Meta),
+}
+impl Parse for InputArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Ident) && (input.peek2(Token![..]) || input.peek2(Token![..=])) {
+            Ok(Self::Range(input.parse()?, input.parse()?, input.parse()?))
+        } else {
+            Ok(Self::Meta(input.parse()?))
+        }
+    }
+}
+
+
+
+/// Defines the order in which the version modules are emitted.
+///
+/// Note that this only affects the layout of the generated code; ordered filters (e.g., `min(...)`) always use the order of
+/// declaration.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum OutputOrder {
+    /// Emits them in the order the versions are declared in the `#[versioning(...)]`-list.
+    Declaration,
+    /// Emits them in the reverse order of declaration, i.e., newest first.
+    Reverse,
+    /// Emits them sorted by the numbers in their identifiers (e.g., `v1_10_0` after `v1_9_0`).
+    Semver,
+}
+
+
+
+/// Defines the pairs of versions to generate conversions between, next to the consecutive ones.
+#[derive(Debug)]
+enum ConversionPairs {
+    /// Between every ordered pair of versions.
+    All,
+    /// Only between the given pairs of `(from, to)`-indices into the list of versions, with the span of the option.
+    Only(Vec<(usize, usize)>, Span),
+}
+
+
+
+/// Describes a struct as it is generated in a particular version, as far as conversions are concerned.
+#[derive(Debug)]
+struct ConversionShape<'f> {
+    /// The path to the struct from the scope of the `#[versioning(...)]`-macro.
+    path:   TokenStream2,
+    /// The fields of the struct in this version.
+    fields: Vec<ConversionField<'f>>,
+}
+
+/// Describes a field of a [`ConversionShape`].
+#[derive(Debug)]
+struct ConversionField<'f> {
+    /// The name of the field.
+    ident:   &'f Ident,
+    /// The type of the field, as resolved for this version.
+    ty:      Cow<'f, Type>,
+    /// The `#[version_convert(...)]`-closure that computes the field, if any.
+    convert: Option<Expr>,
+    /// The cfg-predicate under which the field exists, if any (see [`field_in_version()`]).
+    pred:    Option<TokenStream2>,
+}
+
+
+
+/// Describes a `#[cfg_attr(<pred>, ...)]` with a `version(...)` in it.
+#[derive(Debug)]
+struct CfgAttrVersions {
+    /// The predicate of the `cfg_attr`.
+    pred:    Meta,
+    /// The filters of the nested `version(...)`s.
+    filters: Vec<VersionFilter>,
+    /// The other attributes applied by the `cfg_attr`.
+    rest:    Vec<Meta>,
 }
 
 
 
+/// Defines the value of a `#[version_impl_trait(...)]`-attribute.
+#[derive(Debug)]
+enum ImplTrait {
+    /// The impl implements the given (possibly negated) trait.
+    Trait(Option<Token![!]>, Path),
+    /// The impl is an inherent impl (written as `_`).
+    Inherent,
+}
+impl Parse for ImplTrait {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![_]) {
+            input.parse::<Token![_]>()?;
+            Ok(Self::Inherent)
+        } else {
+            Ok(Self::Trait(input.parse()?, input.parse()?))
+        }
+    }
+}
+
 
 
 /***** HELPER FUNCTIONS *****/
@@ -84,6 +487,40 @@ fn item_attrs(item: &Item) -> Option<&[Attribute]> {
         other => panic!("Encountered unknown Item variant '{other:?}'"),
     }
 }
+/// Gets the attributes of an [`Item`] mutably.
+///
+/// # Arguments
+/// - `item`: A(n) (mutable reference to the) [`Item`] of which to return the attributes.
+///
+/// # Returns
+/// A mutable reference to the list of [`Attribute`]s, or [`None`] if this variant does not have any.
+#[inline]
+fn item_attrs_mut(item: &mut Item) -> Option<&mut Vec<Attribute>> {
+    match item {
+        // All the ones we know
+        Item::Const(ItemConst { attrs, .. })
+        | Item::Enum(ItemEnum { attrs, .. })
+        | Item::ExternCrate(ItemExternCrate { attrs, .. })
+        | Item::Fn(ItemFn { attrs, .. })
+        | Item::ForeignMod(ItemForeignMod { attrs, .. })
+        | Item::Impl(ItemImpl { attrs, .. })
+        | Item::Macro(ItemMacro { attrs, .. })
+        | Item::Mod(ItemMod { attrs, .. })
+        | Item::Static(ItemStatic { attrs, .. })
+        | Item::Struct(ItemStruct { attrs, .. })
+        | Item::Trait(ItemTrait { attrs, .. })
+        | Item::TraitAlias(ItemTraitAlias { attrs, .. })
+        | Item::Type(ItemType { attrs, .. })
+        | Item::Union(ItemUnion { attrs, .. })
+        | Item::Use(ItemUse { attrs, .. }) => Some(attrs),
+
+        // Vertabim doesn't have attrs, unfortunately
+        Item::Verbatim(_) => None,
+
+        // And any others, 'cuz non-exhaustive ;(
+        other => panic!("Encountered unknown Item variant '{other:?}'"),
+    }
+}
 /// Gets the visibility of an [`Item`].
 ///
 /// # Arguments
@@ -116,6 +553,101 @@ fn item_vis(item: &Item) -> Option<&Visibility> {
     }
 }
 
+/// Gets the name of an [`Item`].
+///
+/// # Arguments
+/// - `item`: A(n) (reference to the) [`Item`] of which to return the name.
+///
+/// # Returns
+/// A reference to the [`Ident`], or [`None`] if this variant does not have any.
+#[inline]
+fn item_ident(item: &Item) -> Option<&Ident> {
+    match item {
+        // All the ones we know and have a name
+        Item::Const(ItemConst { ident, .. })
+        | Item::Enum(ItemEnum { ident, .. })
+        | Item::ExternCrate(ItemExternCrate { ident, .. })
+        | Item::Mod(ItemMod { ident, .. })
+        | Item::Static(ItemStatic { ident, .. })
+        | Item::Struct(ItemStruct { ident, .. })
+        | Item::Trait(ItemTrait { ident, .. })
+        | Item::TraitAlias(ItemTraitAlias { ident, .. })
+        | Item::Type(ItemType { ident, .. })
+        | Item::Union(ItemUnion { ident, .. }) => Some(ident),
+        Item::Fn(ItemFn { sig, .. }) => Some(&sig.ident),
+        Item::Macro(ItemMacro { ident, .. }) => ident.as_ref(),
+
+        // All the ones we know that _don't_ have a name
+        Item::ForeignMod(ItemForeignMod { .. }) | Item::Impl(ItemImpl { .. }) | Item::Use(ItemUse { .. }) | Item::Verbatim(_) => None,
+
+        // And any others, 'cuz non-exhaustive ;(
+        other => panic!("Encountered unknown Item variant '{other:?}'"),
+    }
+}
+
+/// Checks whether a visibility would become too restrictive when the item is nested in a version module.
+///
+/// This is the case for private items, but also for those only visible relative to their module (e.g., `pub(super)`), as
+/// that module changes when wrapped. Items that are public or restricted to an absolute path (e.g., `pub(crate)`) are not.
+///
+/// # Arguments
+/// - `vis`: The [`Visibility`] to check.
+///
+/// # Returns
+/// True if the visibility should be promoted to `pub` when wrapping, or false if it can be kept as-is.
+#[inline]
+fn is_private_to_wrapper(vis: &Visibility) -> bool {
+    match vis {
+        Visibility::Inherited => true,
+        Visibility::Restricted(VisRestricted { path, .. }) => !path.segments.first().map(|seg| seg.ident == "crate").unwrap_or(false),
+        Visibility::Public(_) => false,
+    }
+}
+
+/// Finds the visibility of an item that is nested in a version module.
+///
+/// # Arguments
+/// - `vis`: The declared [`Visibility`] of the item.
+/// - `force_public`: Whether to promote the visibility to `pub` if it would become too restrictive (see
+///   [`is_private_to_wrapper()`]). The `pub` is spanned to the declared visibility.
+///
+/// # Returns
+/// The [`Visibility`] to give the item in the version module.
+#[inline]
+fn wrapper_vis<'v>(vis: &'v Visibility, force_public: bool) -> Cow<'v, Visibility> {
+    if force_public && is_private_to_wrapper(vis) {
+        Cow::Owned(Visibility::Public(Pub { span: vis.span() }))
+    } else {
+        Cow::Borrowed(vis)
+    }
+}
+
+/// Finds the visibility of a `use` that is nested in a version module, such that it's visible as far as it was before.
+///
+/// Unlike other items, promoting a private `use` to `pub` would re-export it further than the imported item may be visible,
+/// which is an error. So instead, relative visibilities are moved up one module to escape the wrapper.
+///
+/// # Arguments
+/// - `vis`: The [`Visibility`] of the `use`.
+///
+/// # Returns
+/// The [`Visibility`] to give it in the version module, or [`None`] if it can be kept as-is.
+fn wrapped_use_vis(vis: &Visibility) -> Option<Visibility> {
+    let span: Span = vis.span();
+    match vis {
+        Visibility::Inherited => Some(syn::parse_quote_spanned! { span=> pub(super) }),
+        Visibility::Restricted(VisRestricted { in_token: None, path, .. }) if path.is_ident("self") => {
+            Some(syn::parse_quote_spanned! { span=> pub(super) })
+        },
+        Visibility::Restricted(VisRestricted { in_token: None, path, .. }) if path.is_ident("super") => {
+            Some(syn::parse_quote_spanned! { span=> pub(in super::super) })
+        },
+        // Paths starting with `self` or `super`, which we'd rather not rewrite
+        vis if is_private_to_wrapper(vis) => Some(Visibility::Public(Pub { span })),
+        _ => None,
+    }
+}
+
 /// Gets the attributes of a [`TraitItem`].
 ///
 /// # Arguments
@@ -140,6 +672,30 @@ fn trait_item_attrs(item: &TraitItem) -> Option<&[Attribute]> {
     }
 }
 
+/// Gets the attributes of a [`TraitItem`] mutably.
+///
+/// # Arguments
+/// - `item`: A (mutable reference to the) [`TraitItem`] of which to return the attributes.
+///
+/// # Returns
+/// A mutable reference to the list of [`Attribute`]s, or [`None`] if this variant does not have any.
+#[inline]
+fn trait_item_attrs_mut(item: &mut TraitItem) -> Option<&mut Vec<Attribute>> {
+    match item {
+        // All the ones we know
+        TraitItem::Const(TraitItemConst { attrs, .. })
+        | TraitItem::Fn(TraitItemFn { attrs, .. })
+        | TraitItem::Macro(TraitItemMacro { attrs, .. })
+        | TraitItem::Type(TraitItemType { attrs, .. }) => Some(attrs),
+
+        // Except for the vertabim; that one doesn't have any attrs
+        TraitItem::Verbatim(_) => None,
+
+        // And any others, 'cuz non-exhaustive ;(
+        other => panic!("Encountered unknown TraitItem variant '{other:?}'"),
+    }
+}
+
 /// Gets the attributes of a [`ForeignItem`].
 ///
 /// # Arguments
@@ -164,6 +720,38 @@ fn foreign_item_attrs(item: &ForeignItem) -> Option<&[Attribute]> {
     }
 }
 
+/// Gets the name of a [`TraitItem`].
+///
+/// # Arguments
+/// - `item`: A (reference to the) [`TraitItem`] of which to return the name.
+///
+/// # Returns
+/// A reference to the [`Ident`], or [`None`] if this variant does not have any.
+#[inline]
+fn trait_item_ident(item: &TraitItem) -> Option<&Ident> {
+    match item {
+        TraitItem::Const(TraitItemConst { ident, .. }) | TraitItem::Type(TraitItemType { ident, .. }) => Some(ident),
+        TraitItem::Fn(TraitItemFn { sig, .. }) => Some(&sig.ident),
+        _ => None,
+    }
+}
+
+/// Gets the name of an [`ImplItem`].
+///
+/// # Arguments
+/// - `item`: A (reference to the) [`ImplItem`] of which to return the name.
+///
+/// # Returns
+/// A reference to the [`Ident`], or [`None`] if this variant does not have any.
+#[inline]
+fn impl_item_ident(item: &ImplItem) -> Option<&Ident> {
+    match item {
+        ImplItem::Const(ImplItemConst { ident, .. }) | ImplItem::Type(ImplItemType { ident, .. }) => Some(ident),
+        ImplItem::Fn(ImplItemFn { sig, .. }) => Some(&sig.ident),
+        _ => None,
+    }
+}
+
 /// Gets the attributes of an [`ImplItem`].
 ///
 /// # Arguments
@@ -187,105 +775,514 @@ fn impl_item_attrs(item: &ImplItem) -> Option<&[Attribute]> {
         other => panic!("Encountered unknown ImplItem variant '{other:?}'"),
     }
 }
-
-
-
-/// Parses macro input as a [`VersionList`] and any options given as a key/value pair.
+/// Gets the attributes of an [`ImplItem`] mutably.
 ///
 /// # Arguments
-/// - `tokens`: The [`TokenStream2`] that contains the input to the attribute macro.
+/// - `item`: A (mutable reference to the) [`ImplItem`] of which to return the attributes.
 ///
 /// # Returns
-/// A tuple with a [`VersionList`], containing the given versions, and an [`Options`], containing other configuration.
-///
-/// # Errors
-/// This function can error if the input is not valid.
-fn parse_input(tokens: TokenStream2) -> Result<(VersionList, Options), Diagnostic> {
-    // Parse the tokens as attributes first
-    let metas: Punctuated<Meta, Comma> = match syn::parse::Parser::parse2(Punctuated::parse_terminated, tokens) {
-        Ok(metas) => metas,
-        Err(err) => return Err(Diagnostic::spanned(err.span(), Level::Error, err.to_string())),
-    };
+/// A mutable reference to the list of [`Attribute`]s, or [`None`] if this variant does not have any.
+#[inline]
+fn impl_item_attrs_mut(item: &mut ImplItem) -> Option<&mut Vec<Attribute>> {
+    match item {
+        // All the ones we know
+        ImplItem::Const(ImplItemConst { attrs, .. })
+        | ImplItem::Fn(ImplItemFn { attrs, .. })
+        | ImplItem::Macro(ImplItemMacro { attrs, .. })
+        | ImplItem::Type(ImplItemType { attrs, .. }) => Some(attrs),
 
-    // Parse them
-    let mut versions: VersionList = VersionList(vec![]);
-    let mut opts: Options = Options::default();
-    for meta in metas {
-        // Match the meta
-        match meta {
-            // We assume paths are version identifiers
-            Meta::Path(p) => match p.get_ident() {
-                Some(ident) => versions.0.push(Version(ident.clone())),
-                None => return Err(Diagnostic::spanned(p.span(), Level::Error, format!("Given version number is not a valid identifier"))),
-            },
+        // Except for the vertabim; that one doesn't have any attrs
+        ImplItem::Verbatim(_) => None,
 
-            // Key/Value pairs are settings
-            Meta::NameValue(nv) => {
-                if nv.path.is_ident("features") {
-                    // Parse the value as a boolean literal
-                    let val: bool = if let Expr::Lit(ExprLit { lit: Lit::Bool(LitBool { value, .. }), .. }) = nv.value {
-                        value
-                    } else {
-                        return Err(Diagnostic::spanned(
-                            nv.value.span(),
-                            Level::Error,
-                            "'features' option must be given a boolean (true/false)".into(),
-                        ));
-                    };
+        // And any others, 'cuz non-exhaustive ;(
+        other => panic!("Encountered unknown ImplItem variant '{other:?}'"),
+    }
+}
 
-                    // Store it
-                    opts.features = val;
-                } else if nv.path.is_ident("nest_toplevel_modules") {
-                    // Parse the value as a boolean literal
-                    let val: bool = if let Expr::Lit(ExprLit { lit: Lit::Bool(LitBool { value, .. }), .. }) = nv.value {
-                        value
-                    } else {
-                        return Err(Diagnostic::spanned(
-                            nv.value.span(),
-                            Level::Error,
-                            "'nest_toplevel_modules' option must be given a boolean (true/false)".into(),
-                        ));
-                    };
 
-                    // Store it
-                    opts.nest_toplevel_modules = val;
-                } else {
-                    return Err(Diagnostic::spanned(
-                        nv.path.span(),
-                        Level::Error,
-                        format!("Unknown configuration parameter '{}'", nv.path.span().source_text().unwrap_or_else(|| "???".into())),
-                    ));
-                }
-            },
 
-            // Others we ignore
-            Meta::List(l) => return Err(Diagnostic::spanned(l.span(), Level::Error, "Not a valid options to the `#[versioning(...)]`-macro".into())),
+/// Converts an identifier in `CamelCase` to a name in `UPPER_SNAKE_CASE`.
+///
+/// # Arguments
+/// - `name`: The name to convert.
+///
+/// # Returns
+/// A new [`String`] with the converted name.
+fn to_upper_snake_case(name: &str) -> String {
+    let mut res: String = String::with_capacity(name.len() + 4);
+    let mut prev_lower: bool = false;
+    for c in name.chars() {
+        if c.is_uppercase() && prev_lower {
+            res.push('_');
         }
+        prev_lower = c.is_lowercase() || c.is_ascii_digit();
+        res.extend(c.to_uppercase());
     }
-
-    // Alright return the lot
-    Ok((versions, opts))
+    res
 }
 
-/// Attempts to read the `#[version(...)]`-attribute from the given list of attributes.
+/// Expands a range of versions (e.g., `v1_0_0..=v1_0_5`) into the individual versions.
+///
+/// Both ends must be identical up to and including their last underscore, after which both must be a number. The versions
+/// in between are then found by counting up that number, padded with zeroes to the length of the start's number. The end is
+/// included only for `..=`.
 ///
 /// # Arguments
-/// - `attrs`: The attributes to read.
+/// - `start`: The first version in the range.
+/// - `limits`: The [`RangeLimits`] that determines whether the `end` is included.
+/// - `end`: The last version in the range.
 ///
 /// # Returns
-/// The [`VersionFilterList`] specified in the `#[version(...)]`-macro if it was found, or else [`None`] if the macro wasn't given.
-fn get_version_attr(attrs: &[Attribute]) -> Result<Option<VersionFilter>, Diagnostic> {
-    // Iterate over the attributes
-    for attr in attrs {
-        match &attr.meta {
-            Meta::List(l) => {
-                if l.path.is_ident("version") {
-                    return match l.parse_args() {
-                        Ok(filter) => {
-                            // Before we return, remove from the list
-                            Ok(Some(filter))
-                        },
-                        Err(err) => Err(Diagnostic::spanned(err.span(), Level::Error, err.to_string())),
+/// The list of [`Version`]s in the range.
+///
+/// # Errors
+/// This function errors if the ends of the range were not compatible, or if the range was empty.
+fn expand_version_range(start: &Ident, limits: &RangeLimits, end: &Ident) -> Result<Vec<Version>, Diagnostic> {
+    // Split both ends in a prefix and a number
+    let split = |ident: &Ident| -> Result<(String, String, u64), Diagnostic> {
+        let name: String = ident.to_string();
+        let (prefix, num): (&str, &str) = match name.rfind('_') {
+            Some(pos) => name.split_at(pos + 1),
+            None => ("", name.as_str()),
+        };
+        match num.parse() {
+            Ok(value) => Ok((prefix.into(), num.into(), value)),
+            Err(_) => Err(Diagnostic::spanned(
+                ident.span(),
+                Level::Error,
+                format!("Cannot use version '{name}' in a range, as its last segment '{num}' is not a number"),
+            )),
+        }
+    };
+    let (start_prefix, start_num, start_value): (String, String, u64) = split(start)?;
+    let (end_prefix, _, end_value): (String, String, u64) = split(end)?;
+    if start_prefix != end_prefix {
+        return Err(Diagnostic::spanned(
+            end.span(),
+            Level::Error,
+            format!("Version range end '{end}' must only differ from start '{start}' in its last segment"),
+        ));
+    }
+
+    // Then count up
+    let last: Option<u64> = if matches!(limits, RangeLimits::Closed(_)) { Some(end_value) } else { end_value.checked_sub(1) };
+    let last: u64 = match last {
+        Some(last) if last >= start_value => last,
+        _ => return Err(Diagnostic::spanned(start.span(), Level::Error, format!("Version range '{start}' to '{end}' is empty"))),
+    };
+    let width: usize = start_num.len();
+    Ok((start_value..=last).map(|value| Version(Ident::new(&format!("{start_prefix}{value:0width$}"), start.span()))).collect())
+}
+
+/// Expands a range of semantic versions (e.g., `>=1.0.0, <2.0.0`) into the individual versions.
+///
+/// The versions are named `v<MAJOR>_<MINOR>_<PATCH>`. See [`semver::walk()`](crate::semver::walk()) for how the range is walked.
+///
+/// # Arguments
+/// - `range`: The range to expand.
+/// - `step`: The granularity at which to walk the range (`major`, `minor` or `patch`, where the latter is the default).
+///
+/// # Returns
+/// The list of [`Version`]s in the range.
+///
+/// # Errors
+/// This function errors if the range or step was invalid, or if the `semver` feature was not enabled.
+#[cfg(feature = "semver")]
+fn expand_semver_range(range: &LitStr, step: Option<&LitStr>) -> Result<Vec<Version>, Diagnostic> {
+    use crate::semver::{walk, SemVer, Step};
+
+    let step: Step = match step {
+        Some(step) => step.value().parse().map_err(|err| Diagnostic::spanned(step.span(), Level::Error, err))?,
+        None => Step::Patch,
+    };
+    let semvers: Vec<SemVer> = walk(&range.value(), step).map_err(|err| Diagnostic::spanned(range.span(), Level::Error, err))?;
+    Ok(semvers.into_iter().map(|SemVer(major, minor, patch)| Version(format_ident!("v{major}_{minor}_{patch}", span = range.span()))).collect())
+}
+#[cfg(not(feature = "semver"))]
+fn expand_semver_range(range: &LitStr, _step: Option<&LitStr>) -> Result<Vec<Version>, Diagnostic> {
+    Err(Diagnostic::spanned(range.span(), Level::Error, "'semver' option requires the 'semver' feature of the `versioning` crate".into()))
+}
+
+/// Converts a version identifier to UpperCamelCase, to use as a suffix.
+///
+/// Underscores are removed, except between two digits to keep the numbers apart (e.g., `v1_0_0` becomes `V1_0_0` and
+/// `beta_1` becomes `Beta1`).
+///
+/// # Arguments
+/// - `name`: The version identifier to convert.
+///
+/// # Returns
+/// The converted identifier.
+fn to_upper_camel_case(name: &str) -> String {
+    let name: &str = name.strip_prefix("r#").unwrap_or(name);
+    let mut res: String = String::with_capacity(name.len());
+    for segment in name.split('_').filter(|segment| !segment.is_empty()) {
+        if res.ends_with(|c: char| c.is_ascii_digit()) && segment.starts_with(|c: char| c.is_ascii_digit()) {
+            res.push('_');
+        }
+        let mut chars = segment.chars();
+        if let Some(first) = chars.next() {
+            res.extend(first.to_uppercase());
+            res.extend(chars);
+        }
+    }
+    res
+}
+
+/// Parses the numbers in a version identifier, to sort it semantically.
+///
+/// The identifier is split on underscores, where the first segment may have a non-numeric prefix (e.g., `v1_0_0` becomes
+/// `[1, 0, 0]`).
+///
+/// # Arguments
+/// - `version`: The [`Version`] to parse.
+///
+/// # Returns
+/// The numbers in the version identifier.
+///
+/// # Errors
+/// This function errors if any of the segments was not a number.
+fn semver_key(version: &Version) -> Result<Vec<u64>, Diagnostic> {
+    let name: String = version.0.to_string();
+    let mut key: Vec<u64> = vec![];
+    for (i, segment) in name.split('_').enumerate() {
+        let segment: &str = if i == 0 { segment.trim_start_matches(|c: char| !c.is_ascii_digit()) } else { segment };
+        match segment.parse() {
+            Ok(num) => key.push(num),
+            Err(_) => {
+                return Err(Diagnostic::spanned(
+                    version.0.span(),
+                    Level::Error,
+                    format!("Cannot sort version '{name}' semantically, as '{segment}' is not a number"),
+                ));
+            },
+        }
+    }
+    Ok(key)
+}
+
+/// Parses the value of a key/value-option to the `#[versioning(...)]`-macro as a boolean.
+///
+/// # Arguments
+/// - `name`: The name of the option, used for errors.
+/// - `value`: The [`Expr`] that was given as value.
+///
+/// # Returns
+/// The parsed boolean.
+///
+/// # Errors
+/// This function errors if the `value` was not a boolean literal.
+fn parse_bool_option(name: &str, value: &Expr) -> Result<bool, Diagnostic> {
+    if let Expr::Lit(ExprLit { lit: Lit::Bool(LitBool { value, .. }), .. }) = value {
+        Ok(*value)
+    } else {
+        Err(Diagnostic::spanned(value.span(), Level::Error, format!("'{name}' option must be given a boolean (true/false)")))
+    }
+}
+
+/// Parses the value of a key/value-option to the `#[versioning(...)]`-macro as a string literal.
+///
+/// # Arguments
+/// - `name`: The name of the option, used for errors.
+/// - `value`: The [`Expr`] that was given as value.
+///
+/// # Returns
+/// The parsed [`LitStr`].
+///
+/// # Errors
+/// This function errors if the `value` was not a string literal.
+fn parse_str_option(name: &str, value: &Expr) -> Result<LitStr, Diagnostic> {
+    if let Expr::Lit(ExprLit { lit: Lit::Str(value), .. }) = value {
+        Ok(value.clone())
+    } else {
+        Err(Diagnostic::spanned(value.span(), Level::Error, format!("'{name}' option must be given a string literal")))
+    }
+}
+
+/// Parses the value of the `conversion_pairs` option.
+///
+/// # Arguments
+/// - `pairs`: The [`LitStr`] to parse, which is either `all` or a comma-separated list of `<from> -> <to>`.
+/// - `versions`: The list of versions in total, which the pairs refer to.
+///
+/// # Returns
+/// The parsed [`ConversionPairs`].
+///
+/// # Errors
+/// This function errors if a pair was malformed or referred to an unknown version.
+fn parse_conversion_pairs(pairs: &LitStr, versions: &VersionList) -> Result<ConversionPairs, Diagnostic> {
+    let value: String = pairs.value();
+    if value.trim() == "all" {
+        return Ok(ConversionPairs::All);
+    }
+
+    // Parse the pairs one-by-one
+    let mut res: Vec<(usize, usize)> = vec![];
+    for pair in value.split(',') {
+        let pair: &str = pair.trim();
+        let (from, to): (&str, &str) = match pair.split_once("->") {
+            Some((from, to)) => (from.trim(), to.trim()),
+            None => {
+                return Err(Diagnostic::spanned(pairs.span(), Level::Error, format!("Conversion pair '{pair}' is not of the form '<from> -> <to>'")))
+            },
+        };
+        let mut indices: [usize; 2] = [0; 2];
+        for (index, name) in indices.iter_mut().zip([from, to]) {
+            *index = match versions.index_of(name) {
+                Some(index) => index,
+                None => {
+                    return Err(Diagnostic::spanned(pairs.span(), Level::Error, format!("Unknown version '{name}' in conversion pair '{pair}'")));
+                },
+            };
+        }
+        if indices[0] == indices[1] {
+            return Err(Diagnostic::spanned(pairs.span(), Level::Error, format!("Conversion pair '{pair}' converts a version to itself")));
+        }
+        res.push((indices[0], indices[1]));
+    }
+    Ok(ConversionPairs::Only(res, pairs.span()))
+}
+
+/// Parses macro input as a [`VersionList`] and any options given as a key/value pair.
+///
+/// # Arguments
+/// - `tokens`: The [`TokenStream2`] that contains the input to the attribute macro.
+///
+/// # Returns
+/// A tuple with a [`VersionList`], containing the given versions, and an [`Options`], containing other configuration.
+///
+/// # Errors
+/// This function can error if the input is not valid.
+fn parse_input(tokens: TokenStream2) -> Result<(VersionList, Options), Diagnostic> {
+    // Parse the tokens as attributes (or ranges) first
+    let args: Punctuated<InputArg, Comma> = match syn::parse::Parser::parse2(Punctuated::parse_terminated, tokens) {
+        Ok(args) => args,
+        Err(err) => return Err(Diagnostic::spanned(err.span(), Level::Error, err.to_string())),
+    };
+
+    // Parse them
+    let mut versions: VersionList = VersionList(vec![]);
+    let mut opts: Options = Options::default();
+    let mut semver: Option<LitStr> = None;
+    let mut step: Option<LitStr> = None;
+    let mut assert_sorted: Option<LitStr> = None;
+    let mut conversion_pairs: Option<LitStr> = None;
+    for arg in args {
+        // Ranges are expanded to their versions immediately
+        let meta: Meta = match arg {
+            InputArg::Range(start, limits, end) => {
+                versions.0.extend(expand_version_range(&start, &limits, &end)?);
+                continue;
+            },
+            InputArg::Meta(meta) => meta,
+        };
+
+        // Otherwise, match the meta
+        match meta {
+            // We assume paths are version identifiers
+            Meta::Path(p) => match p.get_ident() {
+                Some(ident) => versions.0.push(Version(ident.clone())),
+                None => return Err(Diagnostic::spanned(p.span(), Level::Error, "Given version number is not a valid identifier".into())),
+            },
+
+            // Key/Value pairs are settings
+            Meta::NameValue(nv) => {
+                if nv.path.is_ident("features") {
+                    opts.features = parse_bool_option("features", &nv.value)?;
+                } else if nv.path.is_ident("nest_toplevel_modules") {
+                    opts.nest_toplevel_modules = parse_bool_option("nest_toplevel_modules", &nv.value)?;
+                } else if nv.path.is_ident("non_exhaustive_from") {
+                    opts.non_exhaustive_from = Some(parse_str_option("non_exhaustive_from", &nv.value)?);
+                } else if nv.path.is_ident("builder") {
+                    opts.builder = parse_bool_option("builder", &nv.value)?;
+                } else if nv.path.is_ident("default") {
+                    opts.default = parse_bool_option("default", &nv.value)?;
+                } else if nv.path.is_ident("field_matrix") {
+                    opts.field_matrix = parse_bool_option("field_matrix", &nv.value)?;
+                } else if nv.path.is_ident("field_diff") {
+                    opts.field_diff = parse_bool_option("field_diff", &nv.value)?;
+                } else if nv.path.is_ident("field_names_const") {
+                    opts.field_names_const = parse_bool_option("field_names_const", &nv.value)?;
+                } else if nv.path.is_ident("require_version") {
+                    opts.require_version = parse_bool_option("require_version", &nv.value)?;
+                } else if nv.path.is_ident("size_asserts") {
+                    opts.size_asserts = parse_bool_option("size_asserts", &nv.value)?;
+                } else if nv.path.is_ident("serde_defaults") {
+                    opts.serde_defaults = parse_bool_option("serde_defaults", &nv.value)?;
+                } else if nv.path.is_ident("schemars") || nv.path.is_ident("schema_fn") {
+                    let name: &str = if nv.path.is_ident("schemars") { "schemars" } else { "schema_fn" };
+                    if !cfg!(feature = "schemars") {
+                        return Err(Diagnostic::spanned(
+                            nv.path.span(),
+                            Level::Error,
+                            format!("'{name}' option requires the 'schemars' feature of the `versioning` crate"),
+                        ));
+                    }
+                    if name == "schemars" {
+                        opts.schemars = parse_bool_option(name, &nv.value)?;
+                    } else {
+                        opts.schema_fn = parse_bool_option(name, &nv.value)?;
+                    }
+                } else if nv.path.is_ident("coalesce") {
+                    opts.coalesce = parse_bool_option("coalesce", &nv.value)?;
+                } else if nv.path.is_ident("cfg_mode") {
+                    opts.cfg_mode = Some(parse_str_option("cfg_mode", &nv.value)?);
+                } else if nv.path.is_ident("normalize_module_case") {
+                    opts.normalize_module_case = parse_bool_option("normalize_module_case", &nv.value)?;
+                } else if nv.path.is_ident("doc_alias_renames") {
+                    opts.doc_alias_renames = parse_bool_option("doc_alias_renames", &nv.value)?;
+                } else if nv.path.is_ident("unit_structs") {
+                    opts.unit_structs = parse_bool_option("unit_structs", &nv.value)?;
+                } else if nv.path.is_ident("deny_warnings") {
+                    opts.deny_warnings = parse_bool_option("deny_warnings", &nv.value)?;
+                } else if nv.path.is_ident("force_public") {
+                    opts.force_public = parse_bool_option("force_public", &nv.value)?;
+                } else if nv.path.is_ident("type_suffix") {
+                    opts.type_suffix = parse_bool_option("type_suffix", &nv.value)?;
+                } else if nv.path.is_ident("conversions") {
+                    opts.conversions = parse_bool_option("conversions", &nv.value)?;
+                } else if nv.path.is_ident("conversion_pairs") {
+                    conversion_pairs = Some(parse_str_option("conversion_pairs", &nv.value)?);
+                } else if nv.path.is_ident("compose_conversions") {
+                    opts.compose_conversions = parse_bool_option("compose_conversions", &nv.value)?;
+                } else if nv.path.is_ident("gen_tests") {
+                    opts.gen_tests = parse_bool_option("gen_tests", &nv.value)?;
+                } else if nv.path.is_ident("semver") {
+                    semver = Some(parse_str_option("semver", &nv.value)?);
+                } else if nv.path.is_ident("step") {
+                    step = Some(parse_str_option("step", &nv.value)?);
+                } else if nv.path.is_ident("assert_sorted") {
+                    let order: LitStr = parse_str_option("assert_sorted", &nv.value)?;
+                    if order.value() != "semver" {
+                        return Err(Diagnostic::spanned(
+                            order.span(),
+                            Level::Error,
+                            format!("Unknown sort order '{}' (expected 'semver')", order.value()),
+                        ));
+                    }
+                    assert_sorted = Some(order);
+                } else if nv.path.is_ident("output_order") {
+                    let order: LitStr = parse_str_option("output_order", &nv.value)?;
+                    opts.output_order = match order.value().as_str() {
+                        "declaration" => OutputOrder::Declaration,
+                        "reverse" => OutputOrder::Reverse,
+                        "semver" => OutputOrder::Semver,
+                        other => {
+                            return Err(Diagnostic::spanned(
+                                order.span(),
+                                Level::Error,
+                                format!("Unknown output order '{other}' (expected 'declaration', 'reverse' or 'semver')"),
+                            ));
+                        },
+                    };
+                } else {
+                    return Err(Diagnostic::spanned(
+                        nv.path.span(),
+                        Level::Error,
+                        format!("Unknown configuration parameter '{}'", nv.path.span().source_text().unwrap_or_else(|| "???".into())),
+                    ));
+                }
+            },
+
+            // Lists are attributes to pass to the generated modules
+            Meta::List(l) => {
+                if l.path.is_ident("pass_attrs") {
+                    match l.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated) {
+                        Ok(attrs) => opts.pass_attrs.extend(attrs),
+                        Err(err) => return Err(Diagnostic::spanned(err.span(), Level::Error, err.to_string())),
+                    }
+                } else {
+                    return Err(Diagnostic::spanned(l.span(), Level::Error, "Not a valid options to the `#[versioning(...)]`-macro".into()));
+                }
+            },
+        }
+    }
+
+    // Derive versions from the semantic version range, if any
+    if let Some(range) = semver {
+        versions.0.extend(expand_semver_range(&range, step.as_ref())?);
+    } else if let Some(step) = step {
+        return Err(Diagnostic::spanned(step.span(), Level::Error, "'step' option can only be used together with the 'semver' option".into()));
+    }
+
+    // Options referring to versions can only be checked once we know them all
+    for version in &versions.0 {
+        opts.version_cfg(version)?;
+        if opts.output_order == OutputOrder::Semver {
+            semver_key(version)?;
+        }
+    }
+    if let Some(cutoff) = &opts.non_exhaustive_from {
+        VersionFilter::AtLeast(cutoff.clone()).verify(&versions)?;
+    }
+    if let Some(pairs) = &conversion_pairs {
+        opts.conversion_pairs = Some(parse_conversion_pairs(pairs, &versions)?);
+    }
+    if assert_sorted.is_some() {
+        // Ordered filters (e.g., `min(...)`) rely on the declaration order, so make sure it's the expected one
+        let mut prev: Option<(&Version, Vec<u64>)> = None;
+        for version in &versions.0 {
+            let key: Vec<u64> = semver_key(version)?;
+            if let Some((prev, prev_key)) = &prev {
+                if key <= *prev_key {
+                    return Err(Diagnostic::spanned(
+                        version.0.span(),
+                        Level::Error,
+                        format!(
+                            "Version '{}' is declared after '{}', but is not semantically newer (required by `assert_sorted`)",
+                            version.0, prev.0
+                        ),
+                    ));
+                }
+            }
+            prev = Some((version, key));
+        }
+    }
+
+    // Alright return the lot
+    Ok((versions, opts))
+}
+
+/// Attempts to read the `#[version(...)]`-attribute from the given list of attributes.
+///
+/// The filters of all attributes in the item are parsed once before any version is generated (see [`FilterCollector`]), so
+/// this looks them up in the `opts` and only parses the ones it missed.
+///
+/// # Arguments
+/// - `attrs`: The attributes to read.
+/// - `opts`: The [`Options`] that contain the parsed filters.
+///
+/// # Returns
+/// The [`VersionFilterList`] specified in the `#[version(...)]`-macro if it was found, or else [`None`] if the macro wasn't given.
+fn get_version_attr(attrs: &[Attribute], opts: &Options) -> Result<Option<VersionFilter>, Diagnostic> {
+    match attrs.iter().find(|attr| matches!(&attr.meta, Meta::List(l) if l.path.is_ident("version"))) {
+        Some(attr) => match opts.filters.get(attr) {
+            Some(filter) => Ok(Some(filter.clone())),
+            None => parse_version_attr(std::slice::from_ref(attr)),
+        },
+        None => Ok(None),
+    }
+}
+
+/// Parses the `#[version(...)]`-attribute from the given list of attributes.
+///
+/// # Arguments
+/// - `attrs`: The attributes to read.
+///
+/// # Returns
+/// The [`VersionFilterList`] specified in the `#[version(...)]`-macro if it was found, or else [`None`] if the macro wasn't given.
+///
+/// # Errors
+/// This function errors if the filter failed to parse.
+fn parse_version_attr(attrs: &[Attribute]) -> Result<Option<VersionFilter>, Diagnostic> {
+    // Iterate over the attributes
+    for attr in attrs {
+        match &attr.meta {
+            Meta::List(l) => {
+                if l.path.is_ident("version") {
+                    return match l.parse_args_with(VersionFilter::parse_trailing) {
+                        Ok(filter) => Ok(Some(filter)),
+                        Err(err) => Err(Diagnostic::spanned(err.span(), Level::Error, err.to_string())),
                     };
                 } else {
                     // Not ours, ignore
@@ -297,289 +1294,2405 @@ fn get_version_attr(attrs: &[Attribute]) -> Result<Option<VersionFilter>, Diagno
             Meta::Path(_) => continue,
         }
     }
-    Ok(None)
+    Ok(None)
+}
+
+/// Resolves the `#[version(else)]`-filters among a list of siblings.
+///
+/// An `else` matches exactly the versions that none of the other siblings with the same name match, i.e., it's resolved
+/// to `not(any(<their filters>))`. Siblings without a `#[version(...)]` are in all versions, leaving none for the `else`.
+///
+/// # Arguments
+/// - `siblings`: The name and attributes of every sibling. Unnamed ones (e.g., `impl`s) are never matched with others.
+///
+/// # Returns
+/// The index of every sibling with an `else`, the span of that `else` and the filter it resolves to.
+///
+/// # Errors
+/// This function errors if an `else` has no (or another `else`) sibling with the same name, or if any filter failed to parse.
+fn resolve_else_filters(siblings: &[(Option<&Ident>, &[Attribute])]) -> Result<Vec<(usize, Span, VersionFilter)>, Diagnostic> {
+    // Find the elses first, before we start resolving them
+    let mut filters: Vec<Option<VersionFilter>> = Vec::with_capacity(siblings.len());
+    for (_, attrs) in siblings {
+        filters.push(parse_version_attr(attrs)?);
+    }
+
+    // Then resolve them
+    let mut res: Vec<(usize, Span, VersionFilter)> = vec![];
+    for (i, ((ident, _), filter)) in siblings.iter().zip(&filters).enumerate() {
+        let (ident, span): (&Ident, Span) = match (ident, filter) {
+            (Some(ident), Some(VersionFilter::Else(span))) => (ident, *span),
+            // Let `verify()` complain about unnamed ones
+            _ => continue,
+        };
+        let mut others: Vec<VersionFilter> = vec![];
+        for (j, ((other, _), filter)) in siblings.iter().zip(&filters).enumerate() {
+            if i == j || *other != Some(ident) {
+                continue;
+            }
+            match filter {
+                Some(VersionFilter::Else(_)) => {
+                    return Err(Diagnostic::spanned(span, Level::Error, format!("There can only be one `#[version(else)]` '{ident}'")));
+                },
+                Some(filter) => others.push(filter.clone()),
+                None => others.push(VersionFilter::Always),
+            }
+        }
+        if others.is_empty() {
+            return Err(Diagnostic::spanned(
+                span,
+                Level::Error,
+                format!("`#[version(else)]` '{ident}' has no siblings with the same name to complement"),
+            ));
+        }
+        res.push((i, span, VersionFilter::Not(Box::new(VersionFilter::Any(others, span)), span)));
+    }
+    Ok(res)
+}
+
+/// Replaces the filter of the `#[version(...)]`-attribute in the given list of attributes.
+///
+/// The tokens of the new filter are spanned to the given span, except for its versions, which keep pointing at where they
+/// were written.
+///
+/// # Arguments
+/// - `attrs`: The attributes to update, of which the first `#[version(...)]` is replaced.
+/// - `filter`: The [`VersionFilter`] to write in it.
+/// - `span`: The [`Span`] to give to the tokens of the filter.
+fn set_version_attr(attrs: &mut [Attribute], filter: &VersionFilter, span: Span) {
+    /// Spans all tokens but literals to the given span.
+    fn respan(tokens: TokenStream2, span: Span) -> TokenStream2 {
+        tokens
+            .into_iter()
+            .map(|mut token| {
+                match &mut token {
+                    TokenTree::Group(group) => {
+                        let mut new: Group = Group::new(group.delimiter(), respan(group.stream(), span));
+                        new.set_span(span);
+                        *group = new;
+                    },
+                    TokenTree::Ident(ident) => ident.set_span(span),
+                    TokenTree::Punct(punct) => punct.set_span(span),
+                    TokenTree::Literal(_) => {},
+                }
+                token
+            })
+            .collect()
+    }
+
+    if let Some(Meta::List(l)) = attrs.iter_mut().map(|attr| &mut attr.meta).find(|meta| meta.path().is_ident("version")) {
+        l.tokens = respan(filter.to_token_stream(), span);
+    }
+}
+
+/// Parses the filters of all `#[version(...)]`-attributes in an item once, before any version is generated.
+///
+/// Every item is filtered once per version, so this avoids re-parsing the same attribute for each of them. The filters are
+/// keyed by the attribute itself, as identical attributes always parse to the same filter. Attributes that fail to parse are
+/// left out, so the error is reported when (and if) they are used.
+///
+/// Any `#[version(else)]` is first rewritten to the concrete filter it stands for (see [`resolve_else_filters()`]), so
+/// everything that reads the filters afterwards (e.g., the conversions) sees the resolved ones. Siblings are the items in the
+/// same module, trait or impl, the named fields of the same struct, union or variant, or the variants of the same enum.
+///
+/// Items with a nested `#[versioning(...)]` are left to that macro.
+struct FilterCollector {
+    /// The filters parsed so far.
+    filters: HashMap<Attribute, VersionFilter>,
+    /// The first error that occurred while resolving an `else`, if any.
+    error:   Option<Diagnostic>,
+}
+impl FilterCollector {
+    /// Resolves the `else`s among a list of siblings, and writes the results back.
+    ///
+    /// # Arguments
+    /// - `siblings`: The siblings to resolve.
+    /// - `parts`: Returns the name and attributes of a sibling.
+    /// - `attrs_mut`: Returns the attributes of a sibling mutably.
+    fn resolve<'t, T: 't>(
+        &mut self,
+        siblings: impl IntoIterator<Item = &'t mut T>,
+        parts: fn(&T) -> (Option<&Ident>, &[Attribute]),
+        attrs_mut: fn(&mut T) -> Option<&mut Vec<Attribute>>,
+    ) {
+        if self.error.is_some() {
+            return;
+        }
+        let mut siblings: Vec<&mut T> = siblings.into_iter().collect();
+        let resolved: Vec<(usize, Span, VersionFilter)> =
+            match resolve_else_filters(&siblings.iter().map(|sibling| parts(sibling)).collect::<Vec<_>>()) {
+                Ok(resolved) => resolved,
+                Err(err) => {
+                    self.error = Some(err);
+                    return;
+                },
+            };
+        for (i, span, filter) in resolved {
+            if let Some(attrs) = attrs_mut(siblings[i]) {
+                set_version_attr(attrs, &filter, span);
+            }
+        }
+    }
+}
+impl VisitMut for FilterCollector {
+    fn visit_item_mut(&mut self, item: &mut Item) {
+        if self.error.is_some()
+            || item_attrs(item).unwrap_or(&[]).iter().any(|attr| attr.path().segments.last().map(|s| s.ident == "versioning").unwrap_or(false))
+        {
+            return;
+        }
+        syn::visit_mut::visit_item_mut(self, item);
+    }
+
+    fn visit_item_mod_mut(&mut self, item: &mut ItemMod) {
+        if let Some((_, items)) = &mut item.content {
+            self.resolve(items.iter_mut(), |item| (item_ident(item), item_attrs(item).unwrap_or(&[])), item_attrs_mut);
+        }
+        syn::visit_mut::visit_item_mod_mut(self, item);
+    }
+
+    fn visit_item_trait_mut(&mut self, item: &mut ItemTrait) {
+        self.resolve(item.items.iter_mut(), |item| (trait_item_ident(item), trait_item_attrs(item).unwrap_or(&[])), trait_item_attrs_mut);
+        syn::visit_mut::visit_item_trait_mut(self, item);
+    }
+
+    fn visit_item_impl_mut(&mut self, item: &mut ItemImpl) {
+        self.resolve(item.items.iter_mut(), |item| (impl_item_ident(item), impl_item_attrs(item).unwrap_or(&[])), impl_item_attrs_mut);
+        syn::visit_mut::visit_item_impl_mut(self, item);
+    }
+
+    fn visit_item_enum_mut(&mut self, item: &mut ItemEnum) {
+        self.resolve(item.variants.iter_mut(), |variant| (Some(&variant.ident), &variant.attrs), |variant| Some(&mut variant.attrs));
+        syn::visit_mut::visit_item_enum_mut(self, item);
+    }
+
+    fn visit_fields_named_mut(&mut self, fields: &mut FieldsNamed) {
+        self.resolve(fields.named.iter_mut(), |field| (field.ident.as_ref(), &field.attrs), |field| Some(&mut field.attrs));
+        syn::visit_mut::visit_fields_named_mut(self, fields);
+    }
+
+    fn visit_attribute_mut(&mut self, attr: &mut Attribute) {
+        if let Ok(Some(filter)) = parse_version_attr(std::slice::from_ref(attr)) {
+            self.filters.insert(attr.clone(), filter);
+        }
+    }
+
+    // Statements are filtered by `generate_filtered_block()` instead, which doesn't support `else`
+    fn visit_block_mut(&mut self, _block: &mut Block) {}
+}
+
+/// Remembers the thing with the given filter if it matches none of the versions, so [`call()`] can warn about it.
+///
+/// Filters that explicitly match nothing (i.e., `never` and `any()`) are assumed to be intentional.
+///
+/// # Arguments
+/// - `attrs`: The attributes of the thing, which identify it.
+/// - `filter`: The [`VersionFilter`] of the thing.
+/// - `versions`: The list of versions in total, none of which the filter may match.
+/// - `span`: The [`Span`] to point the warning at.
+/// - `what`: A description of the thing (e.g., `Field 'foo'`).
+fn check_dead(attrs: &[Attribute], filter: &VersionFilter, versions: &VersionList, span: Span, what: impl FnOnce() -> String) {
+    if matches!(filter, VersionFilter::Never)
+        || matches!(filter, VersionFilter::Any(filters, _) if filters.is_empty())
+        || versions.0.iter().any(|v| filter.matches(versions, v))
+    {
+        return;
+    }
+    DEAD.with(|dead| {
+        let mut dead = dead.borrow_mut();
+        if !dead.iter().any(|(key, _, _)| *key == attrs.as_ptr()) {
+            dead.push((attrs.as_ptr(), span, what()));
+        }
+    });
+}
+
+/// Attempts to read a companion attribute of the form `#[name(<filter>)]` from the given list of attributes.
+///
+/// The same attribute may be given multiple times, in which case the flag is set if any of their filters matches.
+///
+/// # Arguments
+/// - `attrs`: The attributes to read.
+/// - `name`: The name of the companion attribute to look for (e.g., `version_constness`).
+/// - `versions`: The list of versions in total (allows us to define order)
+/// - `version`: The current version to match the filter with.
+///
+/// # Returns
+/// Whether the filter of any of the companion attributes matches the current version, or [`None`] if there were no such
+/// attributes.
+///
+/// # Errors
+/// This function errors if any of the companion attributes failed to parse, or if their filters refer to unknown versions.
+fn get_version_flag(attrs: &[Attribute], name: &str, versions: &VersionList, version: &Version) -> Result<Option<bool>, Diagnostic> {
+    let mut res: Option<bool> = None;
+    for attr in attrs {
+        if let Meta::List(l) = &attr.meta {
+            if l.path.is_ident(name) {
+                let filter: VersionFilter = match l.parse_args_with(VersionFilter::parse_trailing) {
+                    Ok(filter) => filter,
+                    Err(err) => return Err(Diagnostic::spanned(err.span(), Level::Error, err.to_string())),
+                };
+                filter.verify(versions)?;
+                res = Some(res.unwrap_or(false) || filter.matches(versions, version));
+            }
+        }
+    }
+    Ok(res)
+}
+
+/// Attempts to read a companion attribute of the form `#[name(<filter>, <value>)]` from the given list of attributes.
+///
+/// The same attribute may be given multiple times, in which case the first one with a filter matching the current version is used.
+///
+/// # Arguments
+/// - `attrs`: The attributes to read.
+/// - `name`: The name of the companion attribute to look for (e.g., `version_default_value`).
+/// - `versions`: The list of versions in total (allows us to define order)
+/// - `version`: The current version to find the value for.
+///
+/// # Returns
+/// The value of the first companion attribute matching the current version, or [`None`] if there was no such attribute.
+///
+/// # Errors
+/// This function errors if any of the companion attributes failed to parse, or if their filters refer to unknown versions.
+fn get_version_override<T: Parse>(attrs: &[Attribute], name: &str, versions: &VersionList, version: &Version) -> Result<Option<T>, Diagnostic> {
+    // Iterate over the attributes
+    let mut res: Option<T> = None;
+    for attr in attrs {
+        match &attr.meta {
+            Meta::List(l) => {
+                if l.path.is_ident(name) {
+                    // Parse it as a filter, a comma and then the value
+                    let (filter, value): (VersionFilter, T) = match l.parse_args_with(|input: ParseStream| -> syn::Result<(VersionFilter, T)> {
+                        let filter: VersionFilter = input.parse()?;
+                        input.parse::<Token![,]>()?;
+                        let value: T = input.parse()?;
+                        Ok((filter, value))
+                    }) {
+                        Ok(res) => res,
+                        Err(err) => return Err(Diagnostic::spanned(err.span(), Level::Error, err.to_string())),
+                    };
+
+                    // Keep going even if we found one, to verify the remaining ones
+                    filter.verify(versions)?;
+                    if res.is_none() && filter.matches(versions, version) {
+                        res = Some(value);
+                    }
+                } else {
+                    // Not ours, ignore
+                    continue;
+                }
+            },
+            // Not ours, ignore
+            Meta::NameValue(_) => continue,
+            Meta::Path(_) => continue,
+        }
+    }
+    Ok(res)
+}
+
+
+
+/// Resolves the type of a field or type alias, which may be overridden per version with `#[version_type(...)]`.
+///
+/// # Arguments
+/// - `attrs`: The attributes of the field or type alias.
+/// - `ty`: The declared [`Type`], used if no `#[version_type(...)]` matches.
+/// - `versions`: The list of versions in total (allows us to define order)
+/// - `version`: The current version to resolve the type for.
+///
+/// # Returns
+/// The [`Type`] to use for the current version.
+///
+/// # Errors
+/// This function errors if any of the `#[version_type(...)]`-attributes was invalid.
+#[inline]
+fn resolve_type<'t>(attrs: &[Attribute], ty: &'t Type, versions: &VersionList, version: &Version) -> Result<Cow<'t, Type>, Diagnostic> {
+    match get_version_override(attrs, "version_type", versions, version)? {
+        Some(ty) => Ok(Cow::Owned(ty)),
+        None => Ok(Cow::Borrowed(ty)),
+    }
+}
+
+/// Resolves the name of an item, which may be overridden per version with `#[version_rename(...)]`.
+///
+/// # Arguments
+/// - `attrs`: The attributes of the item.
+/// - `ident`: The declared identifier, used if no `#[version_rename(...)]` matches.
+/// - `versions`: The list of versions in total (allows us to define order)
+/// - `version`: The current version to resolve the name for.
+/// - `toplevel`: Whether this is the toplevel item, which is suffixed with the version if `type_suffix` is given.
+/// - `opts`: The [`Options`] that determine whether renamed items are given a `#[doc(alias = "...")]`.
+///
+/// # Returns
+/// A tuple of the identifier to use for the current version and any attributes to inject because of the rename.
+///
+/// # Errors
+/// This function errors if any of the `#[version_rename(...)]`-attributes was invalid.
+fn resolve_ident<'i>(
+    attrs: &[Attribute],
+    ident: &'i Ident,
+    versions: &VersionList,
+    version: &Version,
+    toplevel: bool,
+    opts: &Options,
+) -> Result<(Cow<'i, Ident>, TokenStream2), Diagnostic> {
+    let (name, alias): (Cow<'i, Ident>, TokenStream2) = match get_version_override::<Ident>(attrs, "version_rename", versions, version)? {
+        Some(rename) => {
+            // Keep the old name searchable in the docs, if told to do so
+            let alias: TokenStream2 = if opts.doc_alias_renames && rename != *ident {
+                let name: String = ident.to_string();
+                quote_spanned! { rename.span() => #[doc(alias = #name)] }
+            } else {
+                TokenStream2::new()
+            };
+            (Cow::Owned(rename), alias)
+        },
+        None => (Cow::Borrowed(ident), TokenStream2::new()),
+    };
+
+    // Suffix it with the version, if told to do so
+    if toplevel && opts.type_suffix {
+        Ok((Cow::Owned(format_ident!("{}{}", name, to_upper_camel_case(&version.0.to_string()))), alias))
+    } else {
+        Ok((name, alias))
+    }
+}
+
+
+/// Parses a `#[version_attr(<filter>, <attrs...>)]`-attribute, and returns the attributes it injects for the given version.
+///
+/// # Arguments
+/// - `attr`: The [`Attribute`] to parse. Assumed to be a `version_attr`.
+/// - `versions`: The list of versions in total (allows us to define order)
+/// - `version`: The current version to resolve the attributes for.
+///
+/// # Returns
+/// The list of attributes to inject, which is empty if the filter does not match the current version.
+///
+/// # Errors
+/// This function errors if the `#[version_attr(...)]`-attribute was invalid.
+fn get_version_attr_metas(attr: &Attribute, versions: &VersionList, version: &Version) -> Result<Vec<Meta>, Diagnostic> {
+    // Parse it as a filter, a comma and then the attributes
+    let (filter, metas): (VersionFilter, Punctuated<Meta, Comma>) = match attr.parse_args_with(|input: ParseStream| {
+        let filter: VersionFilter = input.parse()?;
+        input.parse::<Comma>()?;
+        Ok((filter, Punctuated::parse_terminated(input)?))
+    }) {
+        Ok(res) => res,
+        Err(err) => return Err(Diagnostic::spanned(err.span(), Level::Error, err.to_string())),
+    };
+
+    // Only keep them if the filter matches
+    filter.verify(versions)?;
+    if filter.matches(versions, version) {
+        Ok(metas.into_iter().collect())
+    } else {
+        Ok(vec![])
+    }
+}
+
+/// Collects the names imported in some versions only from the `#[version_use_item(...)]`-attributes of a `use`.
+///
+/// # Arguments
+/// - `attrs`: The attributes of the `use`, which may contain `#[version_use_item(<filter>, <names>...)]`s.
+/// - `tree`: The declared [`UseTree`] of the `use`, which must import every gated name.
+/// - `versions`: The list of versions in total (allows us to define order)
+/// - `version`: The current version to resolve for.
+///
+/// # Returns
+/// A map of every gated name to whether it's imported in this version, which is the case if any of its filters match.
+///
+/// # Errors
+/// This function errors if any of the attributes was invalid, or if it lists a name that the `use` doesn't import.
+fn get_version_use_items(attrs: &[Attribute], tree: &UseTree, versions: &VersionList, version: &Version) -> Result<HashMap<Ident, bool>, Diagnostic> {
+    let mut res: HashMap<Ident, bool> = HashMap::new();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("version_use_item")) {
+        // Parse it as a filter, a comma and then the names
+        let (filter, names): (VersionFilter, Punctuated<Ident, Comma>) = match attr.parse_args_with(|input: ParseStream| {
+            let filter: VersionFilter = input.parse()?;
+            input.parse::<Comma>()?;
+            Ok((filter, Punctuated::parse_terminated(input)?))
+        }) {
+            Ok(res) => res,
+            Err(err) => return Err(Diagnostic::spanned(err.span(), Level::Error, err.to_string())),
+        };
+
+        // Mark them as kept if the filter matches
+        filter.verify(versions)?;
+        let matches: bool = filter.matches(versions, version);
+        for name in names {
+            if !use_tree_imports(tree, &name) {
+                return Err(Diagnostic::spanned(
+                    name.span(),
+                    Level::Error,
+                    format!("`#[version_use_item(...)]` lists '{name}', which is not imported by this `use`"),
+                ));
+            }
+            *res.entry(name).or_insert(false) |= matches;
+        }
+    }
+    Ok(res)
+}
+
+/// Checks whether a [`UseTree`] imports a particular name (i.e., as the last segment of any of its paths).
+///
+/// # Arguments
+/// - `tree`: The [`UseTree`] to search.
+/// - `name`: The name to search for.
+///
+/// # Returns
+/// True if `tree` imports `name` (possibly renamed), or false otherwise.
+fn use_tree_imports(tree: &UseTree, name: &Ident) -> bool {
+    match tree {
+        UseTree::Path(UsePath { tree, .. }) => use_tree_imports(tree, name),
+        UseTree::Name(UseName { ident }) | UseTree::Rename(UseRename { ident, .. }) => ident == name,
+        UseTree::Glob(_) => false,
+        UseTree::Group(UseGroup { items, .. }) => items.iter().any(|tree| use_tree_imports(tree, name)),
+    }
+}
+
+/// Removes the names that aren't imported in this version from a [`UseTree`].
+///
+/// # Arguments
+/// - `tree`: The [`UseTree`] to filter.
+/// - `gated`: A map of names to whether they're imported in this version. Names not in it are always kept.
+///
+/// # Returns
+/// The filtered [`UseTree`], or [`None`] if nothing is left of it. Note that groups that lose all their names are kept as
+/// empty groups (i.e., `{}`), which is valid (if useless) Rust.
+fn filter_use_tree(tree: &UseTree, gated: &HashMap<Ident, bool>) -> Option<UseTree> {
+    match tree {
+        UseTree::Path(UsePath { ident, colon2_token, tree }) => Some(UseTree::Path(UsePath {
+            ident:        ident.clone(),
+            colon2_token: *colon2_token,
+            tree:         Box::new(filter_use_tree(tree, gated)?),
+        })),
+        UseTree::Name(UseName { ident }) | UseTree::Rename(UseRename { ident, .. }) => {
+            if gated.get(ident).copied().unwrap_or(true) {
+                Some(tree.clone())
+            } else {
+                None
+            }
+        },
+        UseTree::Glob(_) => Some(tree.clone()),
+        UseTree::Group(UseGroup { brace_token, items }) => {
+            // Rebuild the list to get the commas right
+            let items: Punctuated<UseTree, Comma> = items.iter().filter_map(|tree| filter_use_tree(tree, gated)).collect();
+            Some(UseTree::Group(UseGroup { brace_token: *brace_token, items }))
+        },
+    }
+}
+
+/// Parses a `#[version_derive(...)]`- or `#[version_no_derive(...)]`-attribute and returns the derives it lists for the
+/// given version.
+///
+/// # Arguments
+/// - `attr`: The `#[version_derive(<filter>, <derives>...)]`- or `#[version_no_derive(<filter>, <derives>...)]`-attribute to
+///   parse.
+/// - `versions`: The list of versions in total (allows us to define order)
+/// - `version`: The current version to resolve for.
+///
+/// # Returns
+/// The paths of the derives to inject (or remove), which is empty if the filter didn't match.
+///
+/// # Errors
+/// This function errors if the attribute was invalid.
+fn get_version_derives(attr: &Attribute, versions: &VersionList, version: &Version) -> Result<Vec<Path>, Diagnostic> {
+    // Parse it as a filter, a comma and then the derives
+    let (filter, paths): (VersionFilter, Punctuated<Path, Comma>) = match attr.parse_args_with(|input: ParseStream| {
+        let filter: VersionFilter = input.parse()?;
+        input.parse::<Comma>()?;
+        Ok((filter, Punctuated::parse_terminated(input)?))
+    }) {
+        Ok(res) => res,
+        Err(err) => return Err(Diagnostic::spanned(err.span(), Level::Error, err.to_string())),
+    };
+
+    // Only keep them if the filter matches
+    filter.verify(versions)?;
+    if filter.matches(versions, version) {
+        Ok(paths.into_iter().collect())
+    } else {
+        Ok(vec![])
+    }
+}
+/// Resolves the signature of a function, of which the `const`- and `unsafe`-qualifiers may be given per version with
+/// `#[version_constness(...)]` and `#[version_unsafe(...)]`, respectively.
+///
+/// If either attribute is given, the function has that qualifier in exactly the versions matched by its filter, regardless of
+/// whether it was declared with it. Any other qualifiers (e.g., `async`) are kept as-is.
+///
+/// # Arguments
+/// - `attrs`: The attributes of the function.
+/// - `sig`: The declared [`Signature`], used as-is if neither attribute is given.
+/// - `versions`: The list of versions in total (allows us to define order)
+/// - `version`: The current version to resolve the signature for.
+///
+/// # Returns
+/// The [`Signature`] to use for the current version.
+///
+/// # Errors
+/// This function errors if the `#[version_constness(...)]`- or `#[version_unsafe(...)]`-attribute was invalid.
+fn resolve_sig<'s>(attrs: &[Attribute], sig: &'s Signature, versions: &VersionList, version: &Version) -> Result<Cow<'s, Signature>, Diagnostic> {
+    let is_const: Option<bool> = get_version_flag(attrs, "version_constness", versions, version)?;
+    let is_unsafe: Option<bool> = get_version_flag(attrs, "version_unsafe", versions, version)?;
+    let generics: Cow<Generics> = resolve_generics(attrs, &sig.generics, versions, version)?;
+    if is_const.is_none() && is_unsafe.is_none() && matches!(generics, Cow::Borrowed(_)) {
+        return Ok(Cow::Borrowed(sig));
+    }
+
+    // Update the qualifiers that are overridden
+    let generics: Generics = generics.into_owned();
+    let mut sig: Signature = sig.clone();
+    sig.generics = generics;
+    if let Some(is_const) = is_const {
+        sig.constness = if is_const { Some(Token![const](sig.fn_token.span)) } else { None };
+    }
+    if let Some(is_unsafe) = is_unsafe {
+        sig.unsafety = if is_unsafe { Some(Token![unsafe](sig.fn_token.span)) } else { None };
+    }
+    Ok(Cow::Owned(sig))
+}
+
+/// Merges a where-predicate into generics.
+///
+/// # Arguments
+/// - `generics`: The [`Generics`] to merge into.
+/// - `pred`: The [`WherePredicate`] to merge. If the where-clause already has a predicate for the same bounded type (or
+///   lifetime), its bounds are added to that one instead of adding a separate predicate. Otherwise, if it bounds a type
+///   parameter of the `generics`, its bounds are added inline to that parameter (e.g., `T` becomes `T: Clone`).
+fn merge_predicate(generics: &mut Generics, pred: WherePredicate) {
+    // See if we should add it inline
+    if let WherePredicate::Type(pred) = &pred {
+        let in_where: bool = generics.where_clause.as_ref().map(|where_clause| {
+            where_clause
+                .predicates
+                .iter()
+                .any(|existing| matches!(existing, WherePredicate::Type(existing) if existing.bounded_ty == pred.bounded_ty))
+        }) == Some(true);
+        if let (false, None, Type::Path(TypePath { qself: None, path })) = (in_where, &pred.lifetimes, &pred.bounded_ty) {
+            if let Some(param) = generics.type_params_mut().find(|param| path.is_ident(&param.ident)) {
+                param.colon_token.get_or_insert_with(Default::default);
+                param.bounds.extend(pred.bounds.iter().cloned());
+                return;
+            }
+        }
+    }
+
+    // Otherwise, add it to the where-clause
+    let where_clause: &mut WhereClause = generics.make_where_clause();
+    match pred {
+        WherePredicate::Type(pred) => {
+            let key: (String, String) = (pred.lifetimes.to_token_stream().to_string(), pred.bounded_ty.to_token_stream().to_string());
+            for existing in &mut where_clause.predicates {
+                if let WherePredicate::Type(existing) = existing {
+                    if (existing.lifetimes.to_token_stream().to_string(), existing.bounded_ty.to_token_stream().to_string()) == key {
+                        existing.bounds.extend(pred.bounds);
+                        return;
+                    }
+                }
+            }
+            where_clause.predicates.push(WherePredicate::Type(pred));
+        },
+        WherePredicate::Lifetime(pred) => {
+            for existing in &mut where_clause.predicates {
+                if let WherePredicate::Lifetime(existing) = existing {
+                    if existing.lifetime == pred.lifetime {
+                        existing.bounds.extend(pred.bounds);
+                        return;
+                    }
+                }
+            }
+            where_clause.predicates.push(WherePredicate::Lifetime(pred));
+        },
+        other => where_clause.predicates.push(other),
+    }
+}
+
+/// Resolves the generics of an item for the given version.
+///
+/// Every `#[version_bound(<filter>, "<predicate>")]` whose filter matches adds its where-predicate (e.g.,
+/// `"I: Serialize"`) to the generics. Predicates for a type (or lifetime) that is already bounded in the where-clause are
+/// merged into the existing predicate, and those for other type parameters are added inline to the parameter.
+///
+/// # Arguments
+/// - `attrs`: The attributes of the item, which may contain `#[version_bound(...)]`s.
+/// - `generics`: The [`Generics`] as given.
+/// - `versions`: The list of versions in total (allows us to define order)
+/// - `version`: The current version to resolve for.
+///
+/// # Returns
+/// The [`Generics`] to use for this version, which are only copied if any bounds are added.
+///
+/// # Errors
+/// This function errors if any of the `#[version_bound(...)]`-attributes was invalid.
+fn resolve_generics<'g>(
+    attrs: &[Attribute],
+    generics: &'g Generics,
+    versions: &VersionList,
+    version: &Version,
+) -> Result<Cow<'g, Generics>, Diagnostic> {
+    let mut res: Cow<'g, Generics> = Cow::Borrowed(generics);
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("version_bound")) {
+        let pred: LitStr = match get_version_override(std::slice::from_ref(attr), "version_bound", versions, version)? {
+            Some(pred) => pred,
+            None => continue,
+        };
+        let pred: WherePredicate = match pred.parse() {
+            Ok(pred) => pred,
+            Err(err) => {
+                return Err(Diagnostic::spanned(pred.span(), Level::Error, format!("Failed to parse `#[version_bound(...)]` predicate: {err}")));
+            },
+        };
+        merge_predicate(res.to_mut(), pred);
+    }
+    Ok(res)
+}
+
+
+
+/// Removes the statements from (nested) blocks of which the `#[version(...)]`-attribute doesn't match a version.
+struct BlockFilter<'v> {
+    /// The list of versions in total (allows us to define order)
+    versions: &'v VersionList,
+    /// The current version to filter for.
+    version:  &'v Version,
+    /// The first error that occurred while filtering, if any.
+    error:    Option<Diagnostic>,
+}
+impl VisitMut for BlockFilter<'_> {
+    fn visit_block_mut(&mut self, block: &mut Block) {
+        if self.error.is_some() {
+            return;
+        }
+        let mut stmts: Vec<Stmt> = Vec::with_capacity(block.stmts.len());
+        for mut stmt in std::mem::take(&mut block.stmts) {
+            if let Some(attrs) = stmt_attrs_mut(&mut stmt) {
+                // Parse the filter (without caching, as these are copies)
+                let mut keep: bool = true;
+                for attr in attrs.iter() {
+                    if let Meta::List(l) = &attr.meta {
+                        if l.path.is_ident("version") {
+                            let filter: VersionFilter = match l.parse_args_with(VersionFilter::parse_trailing) {
+                                Ok(filter) => filter,
+                                Err(err) => {
+                                    self.error = Some(Diagnostic::spanned(err.span(), Level::Error, err.to_string()));
+                                    return;
+                                },
+                            };
+                            if let Err(err) = filter.verify(self.versions) {
+                                self.error = Some(err);
+                                return;
+                            }
+                            keep &= filter.matches(self.versions, self.version);
+                        }
+                    }
+                }
+                if !keep {
+                    continue;
+                }
+                attrs.retain(|attr| !attr.path().is_ident("version"));
+            }
+            stmts.push(stmt);
+        }
+        block.stmts = stmts;
+
+        // Continue with any blocks nested in the ones we kept
+        syn::visit_mut::visit_block_mut(self, block);
+    }
+}
+
+/// Gets the attributes of a [`Stmt`] mutably.
+///
+/// # Arguments
+/// - `stmt`: A(n) (mutable reference to the) [`Stmt`] of which to return the attributes.
+///
+/// # Returns
+/// A mutable reference to the attributes of the statement, or [`None`] if it's of a kind that can't have any.
+fn stmt_attrs_mut(stmt: &mut Stmt) -> Option<&mut Vec<Attribute>> {
+    match stmt {
+        Stmt::Local(local) => Some(&mut local.attrs),
+        Stmt::Item(item) => item_attrs_mut(item),
+        Stmt::Macro(mac) => Some(&mut mac.attrs),
+        Stmt::Expr(expr, _) => match expr {
+            Expr::Array(e) => Some(&mut e.attrs),
+            Expr::Assign(e) => Some(&mut e.attrs),
+            Expr::Async(e) => Some(&mut e.attrs),
+            Expr::Await(e) => Some(&mut e.attrs),
+            Expr::Binary(e) => Some(&mut e.attrs),
+            Expr::Block(e) => Some(&mut e.attrs),
+            Expr::Break(e) => Some(&mut e.attrs),
+            Expr::Call(e) => Some(&mut e.attrs),
+            Expr::Cast(e) => Some(&mut e.attrs),
+            Expr::Closure(e) => Some(&mut e.attrs),
+            Expr::Const(e) => Some(&mut e.attrs),
+            Expr::Continue(e) => Some(&mut e.attrs),
+            Expr::Field(e) => Some(&mut e.attrs),
+            Expr::ForLoop(e) => Some(&mut e.attrs),
+            Expr::Group(e) => Some(&mut e.attrs),
+            Expr::If(e) => Some(&mut e.attrs),
+            Expr::Index(e) => Some(&mut e.attrs),
+            Expr::Infer(e) => Some(&mut e.attrs),
+            Expr::Let(e) => Some(&mut e.attrs),
+            Expr::Lit(e) => Some(&mut e.attrs),
+            Expr::Loop(e) => Some(&mut e.attrs),
+            Expr::Macro(e) => Some(&mut e.attrs),
+            Expr::Match(e) => Some(&mut e.attrs),
+            Expr::MethodCall(e) => Some(&mut e.attrs),
+            Expr::Paren(e) => Some(&mut e.attrs),
+            Expr::Path(e) => Some(&mut e.attrs),
+            Expr::Range(e) => Some(&mut e.attrs),
+            Expr::Reference(e) => Some(&mut e.attrs),
+            Expr::Repeat(e) => Some(&mut e.attrs),
+            Expr::Return(e) => Some(&mut e.attrs),
+            Expr::Struct(e) => Some(&mut e.attrs),
+            Expr::Try(e) => Some(&mut e.attrs),
+            Expr::TryBlock(e) => Some(&mut e.attrs),
+            Expr::Tuple(e) => Some(&mut e.attrs),
+            Expr::Unary(e) => Some(&mut e.attrs),
+            Expr::Unsafe(e) => Some(&mut e.attrs),
+            Expr::While(e) => Some(&mut e.attrs),
+            Expr::Yield(e) => Some(&mut e.attrs),
+            // Vertabim doesn't have attrs, and any others are new
+            _ => None,
+        },
+    }
+}
+
+/// Filters the statements in the given function body in accordance to the list of versions.
+///
+/// Statements (in the body itself or in any block nested in it, including those of nested functions) with a
+/// `#[version(...)]`-attribute are only kept if it matches the current version. Note that the compiler only accepts
+/// attributes on some statements (e.g., `let`s, macros and blocks), so other expressions have to be wrapped in a block.
+///
+/// # Arguments
+/// - `block`: The [`Block`] to filter.
+/// - `versions`: The list of versions in total (allows us to define order)
+/// - `version`: The current version to filter for.
+///
+/// # Returns
+/// A copy of the `block` without the statements that aren't in this version.
+///
+/// # Errors
+/// This function errors if any of the `#[version(...)]`-attributes failed to parse or referred to unknown versions.
+fn generate_filtered_block(block: &Block, versions: &VersionList, version: &Version) -> Result<Block, Diagnostic> {
+    let mut block: Block = block.clone();
+    let mut filter: BlockFilter = BlockFilter { versions, version, error: None };
+    filter.visit_block_mut(&mut block);
+    match filter.error {
+        Some(err) => Err(err),
+        None => Ok(block),
+    }
+}
+
+/// Filters the given attributes minus the `#[version(...)]`-attribute (and its companions) and compiles it to a [`TokenStream2`].
+///
+/// A `version(...)` nested in a `#[cfg_attr(<pred>, ...)]` is supported too, and is only applied if `<pred>` holds. This is
+/// done by removing it from the `cfg_attr` and, for versions the filter rejects, emitting a `#[cfg(not(<pred>))]` instead.
+/// Note that this only applies to `version(...)` directly nested in the (outermost) `cfg_attr`; its companion attributes
+/// (e.g., `version_value(...)`) are not supported this way.
+///
+/// Attributes injected by a `#[version_attr(<filter>, <attrs...>)]` are emitted in its place, except for `repr(...)`s. Those
+/// are emitted before any other attribute instead, and replace any declared `#[repr(...)]` for the versions they apply to.
+///
+/// As such, the attributes are emitted in the following order:
+/// 1. Any `repr(...)`s injected by `#[version_attr(...)]`s, in source order;
+/// 2. All other attributes in source order, where `#[version_attr(...)]`s are replaced by the attributes they inject and
+///    `#[version_doc(<filter>, "...")]`s by a `#[doc = "..."]` (if their filter matches) and `#[version_feature("...")]`s by
+///    a `#[cfg(feature = "...")]`. Derives injected by `#[version_derive(<filter>, ...)]`s are merged into the first
+///    `#[derive(...)]`, or emitted as one where the first `#[version_derive(...)]` is if there are no declared derives.
+///    Derives listed by matching `#[version_no_derive(<filter>, ...)]`s are removed from the declared ones, dropping the
+///    `#[derive(...)]` altogether if nothing is left.
+///
+/// Attributes generated because of options (e.g., `#[non_exhaustive]` or `#[doc(alias = "...")]`) are emitted after these
+/// by [`generate_filtered_item()`], except for derives (see [`generate_attrs_with_derives()`]).
+///
+/// # Arguments
+/// - `attrs`: The list of [`Attribute`]s to filter.
+/// - `versions`: The list of versions in total (allows us to define order)
+/// - `version`: The current version to filter for.
+///
+/// # Returns
+/// A new [`TokenStream2`] that encodes the body item but without certain components if filtered out by the version.
+///
+/// # Errors
+/// This function errors if a `version(...)` nested in a `#[cfg_attr(...)]` or a `#[version_attr(...)]` was invalid.
+#[inline]
+fn generate_attrs(attrs: &[Attribute], versions: &VersionList, version: &Version) -> Result<TokenStream2, Diagnostic> {
+    generate_attrs_with_derives(attrs, &[], versions, version)
+}
+
+/// Like [`generate_attrs()`], but injects the given derives in all versions.
+///
+/// They are emitted as one `#[derive(...)]` before all other attributes (but after the `repr(...)`s), so that any of their
+/// helper attributes (e.g., `#[schemars(...)]`) come after it.
+///
+/// # Arguments
+/// - `attrs`: The list of [`Attribute`]s to filter.
+/// - `extra`: The paths of the derives to inject.
+/// - `versions`: The list of versions in total (allows us to define order)
+/// - `version`: The current version to filter for.
+///
+/// # Returns
+/// A new [`TokenStream2`] that encodes the body item but without certain components if filtered out by the version.
+///
+/// # Errors
+/// This function errors if a `version(...)` nested in a `#[cfg_attr(...)]` or a `#[version_attr(...)]` was invalid.
+fn generate_attrs_with_derives(attrs: &[Attribute], extra: &[Path], versions: &VersionList, version: &Version) -> Result<TokenStream2, Diagnostic> {
+    // Find any representations injected for this version first, as they go before anything else
+    let mut reprs: Vec<Meta> = vec![];
+    for attr in attrs {
+        if attr.path().is_ident("version_attr") {
+            reprs.extend(get_version_attr_metas(attr, versions, version)?.into_iter().filter(|m| m.path().is_ident("repr")));
+        }
+    }
+    let mut stream: TokenStream2 = reprs.iter().map(|repr| quote_spanned! { repr.span() => #[#repr] }).collect();
+    if !extra.is_empty() {
+        stream.extend(quote! { #[derive(#(#extra),*)] });
+    }
+
+    // Find any derives injected (or removed) for this version, which are merged with the first `#[derive(...)]` (if any)
+    let mut derives: Vec<Path> = vec![];
+    let mut no_derives: Vec<Path> = vec![];
+    for attr in attrs {
+        if attr.path().is_ident("version_derive") {
+            derives.extend(get_version_derives(attr, versions, version)?);
+        } else if attr.path().is_ident("version_no_derive") {
+            no_derives.extend(get_version_derives(attr, versions, version)?);
+        }
+    }
+    let mut derives: Option<Vec<Path>> = if derives.is_empty() { None } else { Some(derives) };
+    let has_derive: bool = attrs.iter().any(|attr| attr.path().is_ident("derive"));
+
+    // Serialize them all, except the ones we don't like
+    for attr in attrs {
+        // Remove the derives that aren't valid in this version, and merge the injected ones into the first derive (or emit them
+        // where the first injection was if there is none)
+        if attr.path().is_ident("derive") && (derives.is_some() || !no_derives.is_empty()) {
+            let mut paths: Vec<Path> = match attr.parse_args_with(Punctuated::<Path, Comma>::parse_terminated) {
+                Ok(declared) => declared.into_iter().filter(|path| !no_derives.contains(path)).collect(),
+                Err(err) => return Err(Diagnostic::spanned(err.span(), Level::Error, err.to_string())),
+            };
+            paths.extend(derives.take().into_iter().flatten());
+            if !paths.is_empty() {
+                stream.extend(quote_spanned! { attr.span() => #[derive(#(#paths),*)] });
+            }
+            continue;
+        }
+        if !has_derive && attr.path().is_ident("version_derive") {
+            if let Some(derives) = derives.take() {
+                stream.extend(quote_spanned! { attr.span() => #[derive(#(#derives),*)] });
+                continue;
+            }
+        }
+
+        // Inject the (remaining) attributes for this version
+        if attr.path().is_ident("version_attr") {
+            let metas: Vec<Meta> = get_version_attr_metas(attr, versions, version)?.into_iter().filter(|m| !m.path().is_ident("repr")).collect();
+            stream.extend(quote_spanned! { attr.span() => #(#[#metas])* });
+            continue;
+        }
+        // Inject the documentation for this version
+        if attr.path().is_ident("version_doc") {
+            if let Some(doc) = get_version_override::<LitStr>(std::slice::from_ref(attr), "version_doc", versions, version)? {
+                stream.extend(quote_spanned! { attr.span() => #[doc = #doc] });
+            }
+            continue;
+        }
+        // Gate the item on an additional feature
+        if attr.path().is_ident("version_feature") {
+            let feature: LitStr = match attr.parse_args() {
+                Ok(feature) => feature,
+                Err(err) => return Err(Diagnostic::spanned(err.span(), Level::Error, format!("Failed to parse `#[version_feature(...)]`: {err}"))),
+            };
+            stream.extend(quote_spanned! { attr.span() => #[cfg(feature = #feature)] });
+            continue;
+        }
+        // Declared representations are overridden by injected ones
+        if !reprs.is_empty() && attr.path().is_ident("repr") {
+            continue;
+        }
+
+        // See if it's a match
+        if VERSION_ATTRS.iter().any(|name| attr.path().is_ident(name)) {
+            continue;
+        }
+
+        // See if it's a `cfg_attr` with a version in it
+        if let Some(CfgAttrVersions { pred, filters, rest }) = get_cfg_attr_versions(attr)? {
+            // Apply the version filters as cfgs
+            for filter in filters {
+                filter.verify(versions)?;
+                if !filter.matches(versions, version) {
+                    stream.extend(quote_spanned! { attr.span() => #[cfg(not(#pred))] });
+                }
+            }
+
+            // Serialize the remaining attributes, if any
+            if !rest.is_empty() {
+                stream.extend(quote_spanned! { attr.span() => #[cfg_attr(#pred, #(#rest),*)] });
+            }
+            continue;
+        }
+
+        // Otherwise, we can serialize it as-is
+        stream.extend(quote! { #attr });
+    }
+    Ok(stream)
+}
+
+/// Splits a `#[cfg_attr(<pred>, ...)]` with a `version(...)` in it into its parts.
+///
+/// # Arguments
+/// - `attr`: The [`Attribute`] to split.
+///
+/// # Returns
+/// The [`CfgAttrVersions`] describing it, or [`None`] if `attr` isn't a `cfg_attr` with a `version(...)` in it (or not one
+/// we understand).
+///
+/// # Errors
+/// This function errors if any of the nested `version(...)`s failed to parse.
+fn get_cfg_attr_versions(attr: &Attribute) -> Result<Option<CfgAttrVersions>, Diagnostic> {
+    let l = match &attr.meta {
+        Meta::List(l) if l.path.is_ident("cfg_attr") => l,
+        _ => return Ok(None),
+    };
+
+    // Parse the predicate and the attributes applied by it
+    let (pred, metas): (Meta, Punctuated<Meta, Comma>) = match l.parse_args_with(|input: ParseStream| {
+        let pred: Meta = input.parse()?;
+        input.parse::<Comma>()?;
+        Ok((pred, Punctuated::parse_terminated(input)?))
+    }) {
+        Ok(res) => res,
+        // Not one we understand, but the compiler will complain about that later
+        Err(_) => return Ok(None),
+    };
+    if !metas.iter().any(|m| m.path().is_ident("version")) {
+        return Ok(None);
+    }
+
+    // Split the version filters from the rest
+    let mut filters: Vec<VersionFilter> = vec![];
+    let mut rest: Vec<Meta> = Vec::with_capacity(metas.len());
+    for meta in metas {
+        match meta {
+            Meta::List(l) if l.path.is_ident("version") => match l.parse_args_with(VersionFilter::parse_trailing) {
+                Ok(filter) => filters.push(filter),
+                Err(err) => return Err(Diagnostic::spanned(err.span(), Level::Error, err.to_string())),
+            },
+            meta => rest.push(meta),
+        }
+    }
+    Ok(Some(CfgAttrVersions { pred, filters, rest }))
+}
+
+/// Decides whether a field exists in a particular version.
+///
+/// Next to its `#[version(...)]`, this considers any `version(...)` nested in a `#[cfg_attr(<pred>, ...)]` on the field.
+/// In the versions those reject, the field only exists if `<pred>` doesn't hold (see [`generate_attrs()`]).
+///
+/// # Arguments
+/// - `field`: The [`Field`] to check.
+/// - `versions`: The list of versions in total (allows us to define order)
+/// - `version`: The version to check for.
+/// - `opts`: The [`Options`] that contain the parsed filters.
+///
+/// # Returns
+/// [`None`] if the field doesn't exist in the version, or else the cfg-predicate under which it does, which is [`None`] if it
+/// always does.
+///
+/// # Errors
+/// This function errors if any of the field's filters was invalid.
+fn field_in_version(field: &Field, versions: &VersionList, version: &Version, opts: &Options) -> Result<Option<Option<TokenStream2>>, Diagnostic> {
+    if let Some(filter) = get_version_attr(&field.attrs, opts)? {
+        if !filter.matches(versions, version) {
+            return Ok(None);
+        }
+    }
+    let mut preds: Vec<Meta> = vec![];
+    for attr in &field.attrs {
+        if let Some(CfgAttrVersions { pred, filters, rest: _ }) = get_cfg_attr_versions(attr)? {
+            if filters.iter().any(|filter| !filter.matches(versions, version)) {
+                preds.push(pred);
+            }
+        }
+    }
+    Ok(Some(match preds.len() {
+        0 => None,
+        1 => Some(quote! { not(#(#preds)*) }),
+        _ => Some(quote! { not(any(#(#preds),*)) }),
+    }))
+}
+
+/// Generates a `&[&str]`-expression that only lists the names of which the cfg-predicate holds.
+///
+/// # Arguments
+/// - `names`: The names to list, each with the cfg-predicate under which it's listed ([`None`] if it always is).
+///
+/// # Returns
+/// A new [`TokenStream2`] that encodes the expression, which is evaluated at compile time.
+fn generate_cfg_str_slice(names: &[(String, Option<TokenStream2>)]) -> TokenStream2 {
+    if names.iter().all(|(_, pred)| pred.is_none()) {
+        let names = names.iter().map(|(name, _)| name);
+        return quote! { &[#(#names),*] };
+    }
+
+    // Otherwise, build the array in a constant
+    let conds: Vec<TokenStream2> = names
+        .iter()
+        .map(|(_, pred)| match pred {
+            Some(pred) => quote! { ::core::cfg!(#pred) },
+            None => quote! { true },
+        })
+        .collect();
+    let names = names.iter().map(|(name, _)| name);
+    quote! {
+        {
+            const LEN: usize = 0 #(+ #conds as usize)*;
+            #[allow(unused_assignments)]
+            const NAMES: [&str; LEN] = {
+                let mut names: [&str; LEN] = [""; LEN];
+                let mut i: usize = 0;
+                #(if #conds { names[i] = #names; i += 1; })*
+                names
+            };
+            &NAMES
+        }
+    }
+}
+
+/// Checks whether the given attributes derive a particular trait.
+///
+/// # Arguments
+/// - `attrs`: The attributes to search.
+/// - `name`: The name of the trait (e.g., `PartialEq`), which is compared to the last segment of every derived path.
+///
+/// # Returns
+/// True if any `#[derive(...)]` in `attrs` derives `name`, or false otherwise.
+fn derives(attrs: &[Attribute], name: &str) -> bool {
+    attrs.iter().filter(|attr| attr.path().is_ident("derive")).any(|attr| {
+        attr.parse_args_with(Punctuated::<Path, Comma>::parse_terminated)
+            .map(|paths| paths.iter().any(|path| path.segments.last().map(|s| s.ident == name).unwrap_or(false)))
+            .unwrap_or(false)
+    })
+}
+
+/// Checks whether a `#[serde(...)]`-attribute already says how to default a field.
+///
+/// # Arguments
+/// - `attr`: The [`Attribute`] to check. Assumed to be a `serde`.
+///
+/// # Returns
+/// True if it contains `default` (either as `default` or `default = "..."`), or false otherwise.
+fn has_default(attr: &Attribute) -> bool {
+    match &attr.meta {
+        Meta::List(l) => l.tokens.clone().into_iter().any(|token| matches!(token, TokenTree::Ident(ident) if ident == "default")),
+        Meta::Path(_) | Meta::NameValue(_) => false,
+    }
+}
+
+/// Filters the given field in accordance to the list of versions and compiles it to a [`TokenStream2`].
+///
+/// # Arguments
+/// - `field`: The [`Field`] to filter.
+/// - `versions`: The list of versions in total (allows us to define order)
+/// - `version`: The current version to filter for.
+/// - `opts`: The [`Options`] that determine whether to inject `#[serde(default)]`.
+///
+/// # Returns
+/// A new [`TokenStream2`] that encodes the body item but without certain components if filtered out by the version.
+fn generate_filtered_field(field: &Field, versions: &VersionList, version: &Version, opts: &Options) -> Result<Option<TokenStream2>, Diagnostic> {
+    // First, check the item's attributes to see if it has been version filtered
+    let mut added: bool = false;
+    let filter: Option<VersionFilter> = get_version_attr(&field.attrs, opts)?;
+    if filter.is_none() {
+        let what: String = match &field.ident {
+            Some(ident) => format!("Field '{ident}'"),
+            None => "Field".into(),
+        };
+        opts.require_version(field.span(), what)?;
+    }
+    if let Some(filter) = filter {
+        // Next, see if this matches the current version
+        filter.verify(versions)?;
+        let span: Span = field.ident.as_ref().map(Ident::span).unwrap_or_else(|| field.ty.span());
+        check_dead(&field.attrs, &filter, versions, span, || match &field.ident {
+            Some(ident) => format!("Field '{ident}'"),
+            None => "Field".into(),
+        });
+        // Remember if it's missing in any earlier version
+        added = versions.0.iter().take_while(|v| v.0 != version.0).any(|v| !filter.matches(versions, v));
+    }
+    if field_in_version(field, versions, version, opts)?.is_none() {
+        // Filtered oot!
+        return Ok(None);
+    }
+
+    // Otherwise, serialize with adapted attributes
+    let Field { attrs, vis, mutability: _, ident, colon_token, ty } = field;
+    let ty: Cow<Type> = resolve_type(attrs, ty, versions, version)?;
+    let mut stream: TokenStream2 = generate_attrs(attrs, versions, version)?;
+    // Older payloads won't have the field, so let serde default it (unless the user already told it what to do)
+    if opts.serde_defaults && added && ident.is_some() && !attrs.iter().any(|attr| attr.path().is_ident("serde") && has_default(attr)) {
+        stream.extend(quote_spanned! { ident.span() => #[serde(default)] });
+    }
+    stream.extend(quote! { #vis #ident #colon_token #ty });
+    Ok(Some(stream))
+}
+
+/// Finds the first identifier in the given tokens that refers to an item filtered out of the surrounding modules.
+///
+/// Only single-segment paths are considered, as anything else (e.g., `Self::FOO`, `other::FOO` or `foo.FOO`) doesn't
+/// refer to the item in the surrounding module.
+///
+/// # Arguments
+/// - `tokens`: The [`TokenStream2`] to search (recursively).
+///
+/// # Returns
+/// The [`Ident`] of the filtered out item, or [`None`] if the tokens don't refer to any.
+fn find_filtered_out(tokens: TokenStream2) -> Option<Ident> {
+    /// Checks whether the given token is the given punctuation character.
+    #[inline]
+    fn is_punct(token: Option<&TokenTree>, c: char) -> bool { matches!(token, Some(TokenTree::Punct(punct)) if punct.as_char() == c) }
+
+    let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            TokenTree::Ident(ident) => {
+                // Skip it if it's part of a longer path or a field access
+                let prev: Option<&TokenTree> = i.checked_sub(1).and_then(|i| tokens.get(i));
+                if is_punct(prev, '.') || (is_punct(prev, ':') && is_punct(i.checked_sub(2).and_then(|i| tokens.get(i)), ':')) {
+                    continue;
+                }
+                if is_punct(tokens.get(i + 1), ':') && is_punct(tokens.get(i + 2), ':') {
+                    continue;
+                }
+                if FILTERED_OUT.with(|filtered| filtered.borrow().contains(ident)) {
+                    return Some(ident.clone());
+                }
+            },
+            TokenTree::Group(group) => {
+                if let Some(ident) = find_filtered_out(group.stream()) {
+                    return Some(ident);
+                }
+            },
+            TokenTree::Punct(_) | TokenTree::Literal(_) => {},
+        }
+    }
+    None
+}
+
+/// Filters the given enum variant in accordance to the list of versions and compiles it to a [`TokenStream2`].
+///
+/// Note that filtering out unnamed fields shifts the positions of the ones after it. For example, in version `v3_0_0`,
+/// `Variant(#[version("v2_0_0")] String, #[version("v3_0_0")] u64)` becomes `Variant(u64)`, where the `u64` is at index 0 instead
+/// of 1. In that case, a warning is emitted.
+///
+/// Discriminants are emitted as-is, unless overridden by `#[version_discriminant(...)]`. If the discriminant refers to a
+/// constant (or static or function) in the surrounding module that is filtered out in this version, an error is emitted
+/// instead, as it would dangle. Such variants need a `#[version_discriminant(...)]` for the versions without it.
+///
+/// # Arguments
+/// - `variant`: The [`Variant`] to filter.
+/// - `versions`: The list of versions in total (allows us to define order)
+/// - `version`: The current version to filter for.
+/// - `opts`: The [`Options`] that determine how to emit warnings.
+///
+/// # Returns
+/// A new [`TokenStream2`] that encodes the body item but without certain components if filtered out by the version.
+fn generate_filtered_variant(
+    variant: &Variant,
+    versions: &VersionList,
+    version: &Version,
+    opts: &Options,
+) -> Result<Option<TokenStream2>, Diagnostic> {
+    // First, check the item's attributes to see if it has been version filtered
+    let filter: Option<VersionFilter> = get_version_attr(&variant.attrs, opts)?;
+    if filter.is_none() {
+        opts.require_version(variant.ident.span(), format!("Variant '{}'", variant.ident))?;
+    }
+    if let Some(filter) = filter {
+        // Next, see if this matches the current version
+        filter.verify(versions)?;
+        check_dead(&variant.attrs, &filter, versions, variant.ident.span(), || format!("Variant '{}'", variant.ident));
+        if !filter.matches(versions, version) {
+            // Filtered oot!
+            return Ok(None);
+        }
+    }
+
+    // Serialize the attributes as first part of the module
+    let Variant { attrs, ident, fields, discriminant } = variant;
+    let mut stream: TokenStream2 = generate_attrs(attrs, versions, version)?;
+    stream.extend(quote! { #ident });
+    // Serialize the fields, if any
+    match fields {
+        Fields::Named(named) => {
+            // Serialize the field as a whole
+            let FieldsNamed { named, brace_token } = named;
+            let mut children: TokenStream2 = TokenStream2::new();
+            for pair in named.pairs() {
+                // Only add filtered ones too
+                let (field, comma): (&Field, Option<&Comma>) = pair.into_tuple();
+                if let Some(stream) = generate_filtered_field(field, versions, version, opts)? {
+                    children.extend(stream);
+                    children.extend(quote! { #comma });
+                }
+            }
+            // Add the whole thing
+            brace_token.surround(&mut stream, |stream: &mut TokenStream2| stream.extend(children));
+        },
+        Fields::Unnamed(unnamed) => {
+            // Serialize the field as a whole
+            let FieldsUnnamed { unnamed, paren_token } = unnamed;
+            let mut children: TokenStream2 = TokenStream2::new();
+            let mut dropped: Option<usize> = None;
+            let mut shifted: bool = false;
+            for (i, pair) in unnamed.pairs().enumerate() {
+                // Only add filtered ones too
+                let (field, comma): (&Field, Option<&Comma>) = pair.into_tuple();
+                if let Some(stream) = generate_filtered_field(field, versions, version, opts)? {
+                    children.extend(stream);
+                    children.extend(quote! { #comma });
+                    shifted |= dropped.is_some();
+                } else if dropped.is_none() {
+                    dropped = Some(i);
+                }
+            }
+            // Dropping any but the trailing fields changes the positions of the others, which is easily missed when matching
+            if let (Some(i), true) = (dropped, shifted) {
+                opts.warn(
+                    ident.span(),
+                    format!(
+                        "Field {i} of variant '{ident}' is filtered out in version '{}', which shifts the positions of the fields after it \
+                         (consider only filtering out trailing fields, or using named fields instead)",
+                        version.0
+                    ),
+                )?;
+            }
+            // Add the whole thing
+            paren_token.surround(&mut stream, |stream: &mut TokenStream2| stream.extend(children));
+        },
+        Fields::Unit => {},
+    }
+    // Serialize the discriminant, if any, which may be overridden per version
+    let expr: Option<Cow<Expr>> = match get_version_override::<Expr>(attrs, "version_discriminant", versions, version)? {
+        Some(expr) => Some(Cow::Owned(expr)),
+        None => discriminant.as_ref().map(|(_, expr)| Cow::Borrowed(expr)),
+    };
+    if let Some(expr) = expr {
+        let eq_token: Token![=] = discriminant.as_ref().map(|(eq_token, _)| *eq_token).unwrap_or_else(|| Token![=](expr.span()));
+        if let Some(name) = find_filtered_out(expr.to_token_stream()) {
+            return Err(Diagnostic::spanned(
+                name.span(),
+                Level::Error,
+                format!(
+                    "Discriminant of variant '{ident}' refers to '{name}', which is filtered out in version '{}' (consider giving a \
+                     `#[version_discriminant(...)]` for it)",
+                    version.0
+                ),
+            ));
+        }
+        stream.extend(quote! { #eq_token #expr });
+    }
+
+    // Done!
+    Ok(Some(stream))
+}
+
+/// Filters the given trait item in accordance to the list of versions and compiles it to a [`TokenStream2`].
+///
+/// # Arguments
+/// - `item`: The [`TraitItem`] to filter.
+/// - `versions`: The list of versions in total (allows us to define order)
+/// - `version`: The current version to filter for.
+///
+/// # Returns
+/// A new [`TokenStream2`] that encodes the body item but without certain components if filtered out by the version.
+fn generate_filtered_trait_item(
+    item: &TraitItem,
+    versions: &VersionList,
+    version: &Version,
+    opts: &Options,
+) -> Result<Option<TokenStream2>, Diagnostic> {
+    // First, check the item's attributes to see if it has been version filtered
+    if let Some(attrs) = trait_item_attrs(item) {
+        if let Some(filter) = get_version_attr(attrs, opts)? {
+            // Next, see if this matches the current version
+            filter.verify(versions)?;
+            check_dead(attrs, &filter, versions, item.span(), || "Trait item".into());
+            if !filter.matches(versions, version) {
+                // Filtered oot!
+                return Ok(None);
+            }
+        }
+    }
+
+    // Match after all (third time we're writing this) to filter oot some attributes
+    match item {
+        TraitItem::Const(TraitItemConst { attrs, const_token, ident, generics, colon_token, ty, default, semi_token }) => {
+            // The default may be overridden per version, and `#[version_provided(...)]` decides whether it's there at all
+            let value: Option<Cow<Expr>> = match get_version_override(attrs, "version_value", versions, version)? {
+                Some(expr) => Some(Cow::Owned(expr)),
+                None => default.as_ref().map(|(_, expr)| Cow::Borrowed(expr)),
+            };
+            let value: Option<Cow<Expr>> = match (get_version_flag(attrs, "version_provided", versions, version)?, value) {
+                (Some(false), _) => None,
+                (Some(true), None) => {
+                    return Err(Diagnostic::spanned(
+                        ident.span(),
+                        Level::Error,
+                        format!(
+                            "Associated constant '{ident}' is provided in version '{}', but has no default value (give it one, or add a \
+                             `#[version_value(...)]` for that version)",
+                            version.0
+                        ),
+                    ));
+                },
+                (_, value) => value,
+            };
+
+            let mut stream: TokenStream2 = generate_attrs(attrs, versions, version)?;
+            stream.extend(quote! { #const_token #ident #generics #colon_token #ty });
+            if let Some(expr) = value {
+                let eq_token: Token![=] = default.as_ref().map(|(eq_token, _)| *eq_token).unwrap_or_else(|| Token![=](expr.span()));
+                stream.extend(quote! { #eq_token #expr })
+            }
+            stream.extend(quote! { #semi_token });
+            Ok(Some(stream))
+        },
+        TraitItem::Fn(TraitItemFn { attrs, sig, default, semi_token }) => {
+            let default: Option<Block> = default.as_ref().map(|block| generate_filtered_block(block, versions, version)).transpose()?;
+            let mut stream: TokenStream2 = generate_attrs(attrs, versions, version)?;
+            stream.extend(quote! { #sig #default #semi_token });
+            Ok(Some(stream))
+        },
+        TraitItem::Macro(TraitItemMacro { attrs, mac, semi_token }) => {
+            let mut stream: TokenStream2 = generate_attrs(attrs, versions, version)?;
+            stream.extend(quote! { #mac #semi_token });
+            Ok(Some(stream))
+        },
+        TraitItem::Type(TraitItemType { attrs, type_token, ident, generics, colon_token, bounds, default, semi_token }) => {
+            let mut stream: TokenStream2 = generate_attrs(attrs, versions, version)?;
+            stream.extend(quote! { #type_token #ident #generics #colon_token #bounds });
+            if let Some((eq_token, expr)) = default {
+                stream.extend(quote! { #eq_token #expr })
+            }
+            // The where-clause isn't serialized with the generics, and goes after the default (e.g., for GATs)
+            let where_clause: &Option<WhereClause> = &generics.where_clause;
+            stream.extend(quote! { #where_clause #semi_token });
+            Ok(Some(stream))
+        },
+
+        // Vertabim is passed as-is
+        TraitItem::Verbatim(v) => Ok(Some(quote! { #v })),
+
+        // And any others, 'cuz non-exhaustive ;(
+        other => panic!("Encountered unknown TraitItem variant '{other:?}'"),
+    }
+}
+
+/// Filters the given foreign item in accordance to the list of versions and compiles it to a [`TokenStream2`].
+///
+/// # Arguments
+/// - `item`: The [`ForeignItem`] to filter.
+/// - `versions`: The list of versions in total (allows us to define order)
+/// - `version`: The current version to filter for.
+///
+/// # Returns
+/// A new [`TokenStream2`] that encodes the body item but without certain components if filtered out by the version.
+fn generate_filtered_foreign_item(
+    item: &ForeignItem,
+    versions: &VersionList,
+    version: &Version,
+    opts: &Options,
+) -> Result<Option<TokenStream2>, Diagnostic> {
+    // First, check the item's attributes to see if it has been version filtered
+    if let Some(attrs) = foreign_item_attrs(item) {
+        if let Some(filter) = get_version_attr(attrs, opts)? {
+            // Next, see if this matches the current version
+            filter.verify(versions)?;
+            check_dead(attrs, &filter, versions, item.span(), || "Foreign item".into());
+            if !filter.matches(versions, version) {
+                // Filtered oot!
+                return Ok(None);
+            }
+        }
+    }
+
+    // Also needs to be manually matches to skip attributes
+    match item {
+        ForeignItem::Fn(ForeignItemFn { attrs, vis, sig, semi_token }) => {
+            let mut stream: TokenStream2 = generate_attrs(attrs, versions, version)?;
+            stream.extend(quote! { #vis #sig #semi_token });
+            Ok(Some(stream))
+        },
+        ForeignItem::Macro(ForeignItemMacro { attrs, mac, semi_token }) => {
+            let mut stream: TokenStream2 = generate_attrs(attrs, versions, version)?;
+            stream.extend(quote! { #mac #semi_token });
+            Ok(Some(stream))
+        },
+        ForeignItem::Static(ForeignItemStatic { attrs, vis, static_token, mutability, ident, colon_token, ty, semi_token }) => {
+            let mut stream: TokenStream2 = generate_attrs(attrs, versions, version)?;
+            stream.extend(quote! { #vis #static_token #mutability #ident #colon_token #ty #semi_token });
+            Ok(Some(stream))
+        },
+        ForeignItem::Type(ForeignItemType { attrs, vis, type_token, ident, generics, semi_token }) => {
+            let mut stream: TokenStream2 = generate_attrs(attrs, versions, version)?;
+            stream.extend(quote! { #vis #type_token #ident #generics #semi_token });
+            Ok(Some(stream))
+        },
+
+        // Vertabim is passed as-is
+        ForeignItem::Verbatim(v) => Ok(Some(quote! { #v })),
+
+        // And any others, 'cuz non-exhaustive ;(
+        other => panic!("Encountered unknown ForeignItem variant '{other:?}'"),
+    }
+}
+
+/// Filters the given impl item in accordance to the list of versions and compiles it to a [`TokenStream2`].
+///
+/// # Arguments
+/// - `item`: The [`ImplItem`] to filter.
+/// - `versions`: The list of versions in total (allows us to define order)
+/// - `version`: The current version to filter for.
+///
+/// # Returns
+/// A new [`TokenStream2`] that encodes the body item but without certain components if filtered out by the version.
+fn generate_filtered_impl_item(
+    item: &ImplItem,
+    versions: &VersionList,
+    version: &Version,
+    opts: &Options,
+) -> Result<Option<TokenStream2>, Diagnostic> {
+    // First, check the item's attributes to see if it has been version filtered
+    if let Some(attrs) = impl_item_attrs(item) {
+        if let Some(filter) = get_version_attr(attrs, opts)? {
+            // Next, see if this matches the current version
+            filter.verify(versions)?;
+            check_dead(attrs, &filter, versions, item.span(), || "Impl item".into());
+            if !filter.matches(versions, version) {
+                // Filtered oot!
+                return Ok(None);
+            }
+        }
+    }
+
+    // Also needs to be manually matches to skip attributes
+    match item {
+        ImplItem::Const(ImplItemConst { attrs, vis, defaultness, const_token, ident, generics, colon_token, ty, eq_token, expr, semi_token }) => {
+            let mut stream: TokenStream2 = generate_attrs(attrs, versions, version)?;
+            stream.extend(quote! { #vis #defaultness #const_token #ident #generics #colon_token #ty #eq_token #expr #semi_token });
+            Ok(Some(stream))
+        },
+        ImplItem::Fn(ImplItemFn { attrs, vis, defaultness, sig, block }) => {
+            let sig: Cow<Signature> = resolve_sig(attrs, sig, versions, version)?;
+            let block: Block = generate_filtered_block(block, versions, version)?;
+            let mut stream: TokenStream2 = generate_attrs(attrs, versions, version)?;
+            stream.extend(quote! { #vis #defaultness #sig #block });
+            Ok(Some(stream))
+        },
+        ImplItem::Macro(ImplItemMacro { attrs, mac, semi_token }) => {
+            let mut stream: TokenStream2 = generate_attrs(attrs, versions, version)?;
+            stream.extend(quote! { #mac #semi_token });
+            Ok(Some(stream))
+        },
+        ImplItem::Type(ImplItemType { attrs, vis, defaultness, type_token, ident, generics, eq_token, ty, semi_token }) => {
+            let mut stream: TokenStream2 = generate_attrs(attrs, versions, version)?;
+            // The where-clause isn't serialized with the generics, and goes after the type (e.g., for GATs)
+            let where_clause: &Option<WhereClause> = &generics.where_clause;
+            stream.extend(quote! { #vis #defaultness #type_token #ident #generics #eq_token #ty #where_clause #semi_token });
+            Ok(Some(stream))
+        },
+
+        // Vertabim is passed as-is
+        ImplItem::Verbatim(v) => Ok(Some(quote! { #v })),
+
+        // And any others, 'cuz non-exhaustive ;(
+        other => panic!("Encountered unknown ImplItem variant '{other:?}'"),
+    }
+}
+
+/// Generates a builder for a struct with named fields.
+///
+/// # Arguments
+/// - `vis`: The [`Visibility`] of the struct, which is also used for the builder.
+/// - `ident`: The identifier of the struct to build.
+/// - `generics`: The [`Generics`] of the struct to build.
+/// - `fields`: The [`Field`]s that survived filtering for the current version, each with the cfg-predicate under which it
+///   exists (see [`field_in_version()`]).
+/// - `versions`: The list of versions in total (allows us to define order)
+/// - `version`: The current version to generate the builder for.
+///
+/// # Returns
+/// A new [`TokenStream2`] that encodes the builder struct and its impls.
+///
+/// # Errors
+/// This function errors if any of the fields' `#[version_type(...)]`-attributes was invalid, or if a field is named after one of
+/// the builder's own methods (i.e., `new` or `build`).
+fn generate_builder(
+    vis: &Visibility,
+    ident: &Ident,
+    generics: &Generics,
+    fields: &[(&Field, Option<TokenStream2>)],
+    versions: &VersionList,
+    version: &Version,
+) -> Result<TokenStream2, Diagnostic> {
+    let builder: Ident = format_ident!("{}Builder", ident);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    // Collect the parts we need per field
+    let idents: Vec<&Ident> = fields.iter().filter_map(|(f, _)| f.ident.as_ref()).collect();
+    let cfgs: Vec<TokenStream2> = fields.iter().map(|(_, pred)| pred.as_ref().map(|pred| quote! { #[cfg(#pred)] }).unwrap_or_default()).collect();
+    if let Some(field) = idents.iter().find(|i| **i == "new" || **i == "build") {
+        return Err(Diagnostic::spanned(
+            field.span(),
+            Level::Error,
+            format!("Field '{field}' of '{ident}' collides with the `{builder}::{field}()`-method of its builder (rename the field, or disable `builder`)"),
+        ));
+    }
+    let mut types: Vec<Cow<Type>> = Vec::with_capacity(fields.len());
+    for (field, _) in fields {
+        types.push(resolve_type(&field.attrs, &field.ty, versions, version)?);
+    }
+    let missing: Vec<String> = idents.iter().map(|i| format!("Missing value for field '{i}' of '{ident}'")).collect();
+
+    // Serialize the lot
+    let builder_doc: String = format!("Builds a [`{ident}`] field-by-field.");
+    Ok(quote! {
+        #[doc = #builder_doc]
+        #vis struct #builder #impl_generics #where_clause {
+            #(#cfgs #idents: ::std::option::Option<#types>,)*
+        }
+        impl #impl_generics ::std::default::Default for #builder #ty_generics #where_clause {
+            #[inline]
+            fn default() -> Self { Self { #(#cfgs #idents: ::std::option::Option::None,)* } }
+        }
+        impl #impl_generics #builder #ty_generics #where_clause {
+            /// Creates a new builder with none of the fields set.
+            #[inline]
+            pub fn new() -> Self { ::std::default::Default::default() }
+
+            #(
+                #cfgs
+                #[doc = concat!("Sets the value of the `", stringify!(#idents), "`-field.")]
+                #[inline]
+                pub fn #idents(mut self, value: #types) -> Self {
+                    self.#idents = ::std::option::Option::Some(value);
+                    self
+                }
+            )*
+
+            /// Builds the struct out of the set fields.
+            ///
+            /// # Errors
+            /// This function errors if not all fields have been given a value.
+            #[inline]
+            pub fn build(self) -> ::std::result::Result<#ident #ty_generics, &'static str> {
+                ::std::result::Result::Ok(#ident {
+                    #(#cfgs #idents: match self.#idents {
+                        ::std::option::Option::Some(value) => value,
+                        ::std::option::Option::None => return ::std::result::Result::Err(#missing),
+                    },)*
+                })
+            }
+        }
+    })
+}
+
+/// Generates a [`Default`]-implementation for a struct.
+///
+/// # Arguments
+/// - `ident`: The identifier of the struct to implement [`Default`] for.
+/// - `generics`: The [`Generics`] of the struct.
+/// - `fields`: The [`Fields`] of the struct, which determine how it is constructed.
+/// - `kept`: The [`Field`]s that survived filtering for the current version, each with the cfg-predicate under which it
+///   exists (see [`field_in_version()`]).
+/// - `unit`: Whether the struct is collapsed to a unit struct in this version (see `unit_structs`), regardless of `fields`.
+/// - `versions`: The list of versions in total (allows us to define order)
+/// - `version`: The current version to generate the implementation for.
+///
+/// # Returns
+/// A new [`TokenStream2`] that encodes the implementation.
+///
+/// # Errors
+/// This function errors if any of the `#[version_default_value(...)]`-attributes was invalid, or if a field of a tuple struct
+/// only exists under a cfg-predicate (as we can't tell the other fields' positions).
+fn generate_default(
+    ident: &Ident,
+    generics: &Generics,
+    fields: &Fields,
+    kept: &[(&Field, Option<TokenStream2>)],
+    unit: bool,
+    versions: &VersionList,
+    version: &Version,
+) -> Result<TokenStream2, Diagnostic> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    // Find the value for every field
+    let values: Vec<TokenStream2> = get_default_values(kept, versions, version)?;
+
+    // Construct the struct in the appropriate way
+    let body: TokenStream2 = match fields {
+        _ if unit => quote! { Self },
+        Fields::Named(_) => {
+            let idents: Vec<&Option<Ident>> = kept.iter().map(|(f, _)| &f.ident).collect();
+            let cfgs = kept.iter().map(|(_, pred)| pred.as_ref().map(|pred| quote! { #[cfg(#pred)] }).unwrap_or_default());
+            quote! { Self { #(#cfgs #idents: #values,)* } }
+        },
+        Fields::Unnamed(_) => {
+            if let Some((field, _)) = kept.iter().find(|(_, pred)| pred.is_some()) {
+                return Err(Diagnostic::spanned(
+                    field.ty.span(),
+                    Level::Error,
+                    format!("Cannot generate a `Default` for tuple struct '{ident}' with fields that only exist under a `cfg_attr`"),
+                ));
+            }
+            quote! { Self(#(#values,)*) }
+        },
+        Fields::Unit => quote! { Self },
+    };
+    Ok(quote! {
+        impl #impl_generics ::std::default::Default for #ident #ty_generics #where_clause {
+            #[inline]
+            fn default() -> Self { #body }
+        }
+    })
+}
+
+/// Finds the default value of every field of a struct in a particular version.
+///
+/// # Arguments
+/// - `kept`: The [`Field`]s that survived filtering for the current version.
+/// - `versions`: The list of versions in total (allows us to define order)
+/// - `version`: The current version to find the values for.
+///
+/// # Returns
+/// The expression given by the field's `#[version_default_value(...)]`, or else [`Default::default()`], for every field.
+///
+/// # Errors
+/// This function errors if any of the `#[version_default_value(...)]`-attributes was invalid.
+fn get_default_values(kept: &[(&Field, Option<TokenStream2>)], versions: &VersionList, version: &Version) -> Result<Vec<TokenStream2>, Diagnostic> {
+    let mut values: Vec<TokenStream2> = Vec::with_capacity(kept.len());
+    for (field, _) in kept {
+        match get_version_override::<Expr>(&field.attrs, "version_default_value", versions, version)? {
+            Some(expr) => values.push(quote! { #expr }),
+            None => values.push(quote! { ::std::default::Default::default() }),
+        }
+    }
+    Ok(values)
+}
+
+/// Generates a constant describing in which versions the fields of a struct exist.
+///
+/// Versions in which a field only exists under a cfg-predicate (see [`field_in_version()`]) are only listed if it holds.
+///
+/// # Arguments
+/// - `vis`: The [`Visibility`] of the struct, which is also used for the constant.
+/// - `ident`: The identifier of the struct.
+/// - `fields`: The [`Fields`] of the struct. Unnamed fields are named after their index.
+/// - `versions`: The list of versions in total, all of which are checked for every field.
+/// - `opts`: The [`Options`] that contain the parsed filters.
+///
+/// # Returns
+/// A new [`TokenStream2`] that encodes a `<STRUCT>_FIELD_MATRIX: &[(&str, &[&str])]`-constant.
+///
+/// # Errors
+/// This function errors if any of the fields' `#[version(...)]`-attributes was invalid.
+fn generate_field_matrix(
+    vis: &Visibility,
+    ident: &Ident,
+    fields: &Fields,
+    versions: &VersionList,
+    opts: &Options,
+) -> Result<TokenStream2, Diagnostic> {
+    // Find for every field the versions it lives in
+    let mut names: Vec<String> = Vec::with_capacity(fields.len());
+    let mut present: Vec<TokenStream2> = Vec::with_capacity(fields.len());
+    for (i, field) in fields.iter().enumerate() {
+        if let Some(filter) = get_version_attr(&field.attrs, opts)? {
+            filter.verify(versions)?;
+        }
+        names.push(field.ident.as_ref().map(Ident::to_string).unwrap_or_else(|| i.to_string()));
+        let mut lives: Vec<(String, Option<TokenStream2>)> = Vec::with_capacity(versions.0.len());
+        for version in &versions.0 {
+            if let Some(pred) = field_in_version(field, versions, version, opts)? {
+                lives.push((version.0.to_string(), pred));
+            }
+        }
+        present.push(generate_cfg_str_slice(&lives));
+    }
+
+    // Serialize it as a constant
+    let name: Ident = format_ident!("{}_FIELD_MATRIX", to_upper_snake_case(&ident.to_string()));
+    let doc: String = format!("Lists for every field of [`{ident}`] the versions in which it exists.");
+    Ok(quote! {
+        #[doc = #doc]
+        #vis const #name: &[(&str, &[&str])] = &[#((#names, #present)),*];
+    })
 }
 
+/// Generates a constant listing which fields of a struct are added and removed between consecutive versions.
+///
+/// Fields that only exist under a cfg-predicate in either version (see [`field_in_version()`]) are only listed if that makes
+/// them added or removed.
+///
+/// # Arguments
+/// - `vis`: The [`Visibility`] of the struct, which is also used for the constant.
+/// - `ident`: The identifier of the struct.
+/// - `fields`: The [`Fields`] of the struct. Unnamed fields are named after their index.
+/// - `versions`: The list of versions in total, of which every consecutive pair is compared.
+/// - `opts`: The [`Options`] that contain the parsed filters.
+///
+/// # Returns
+/// A new [`TokenStream2`] that encodes a `<STRUCT>_FIELD_DIFF: &[(&str, &str, &[&str], &[&str])]`-constant, which lists
+/// `(from, to, added, removed)` for every pair of consecutive versions.
+///
+/// # Errors
+/// This function errors if any of the fields' `#[version(...)]`-attributes was invalid.
+fn generate_field_diff(vis: &Visibility, ident: &Ident, fields: &Fields, versions: &VersionList, opts: &Options) -> Result<TokenStream2, Diagnostic> {
+    // Find for every field whether it lives in every version
+    let mut present: Vec<(String, Vec<Option<Option<TokenStream2>>>)> = Vec::with_capacity(fields.len());
+    for (i, field) in fields.iter().enumerate() {
+        if let Some(filter) = get_version_attr(&field.attrs, opts)? {
+            filter.verify(versions)?;
+        }
+        let mut lives: Vec<Option<Option<TokenStream2>>> = Vec::with_capacity(versions.0.len());
+        for version in &versions.0 {
+            lives.push(field_in_version(field, versions, version, opts)?);
+        }
+        present.push((field.ident.as_ref().map(Ident::to_string).unwrap_or_else(|| i.to_string()), lives));
+    }
+
+    // Compare every boundary, where a field is added if it doesn't exist before it but does after it
+    let appears = |before: &Option<Option<TokenStream2>>, after: &Option<Option<TokenStream2>>| -> Option<Option<TokenStream2>> {
+        match (before, after) {
+            (_, None) | (Some(None), _) => None,
+            (None, Some(after)) => Some(after.clone()),
+            (Some(Some(before)), Some(None)) => Some(Some(quote! { not(#before) })),
+            (Some(Some(before)), Some(Some(after))) => Some(Some(quote! { all(not(#before), #after) })),
+        }
+    };
+    let mut boundaries: Vec<TokenStream2> = Vec::with_capacity(versions.0.len().saturating_sub(1));
+    for (i, pair) in versions.0.windows(2).enumerate() {
+        let (from, to): (String, String) = (pair[0].0.to_string(), pair[1].0.to_string());
+        let added: Vec<(String, Option<TokenStream2>)> =
+            present.iter().filter_map(|(name, p)| appears(&p[i], &p[i + 1]).map(|pred| (name.clone(), pred))).collect();
+        let removed: Vec<(String, Option<TokenStream2>)> =
+            present.iter().filter_map(|(name, p)| appears(&p[i + 1], &p[i]).map(|pred| (name.clone(), pred))).collect();
+        let (added, removed): (TokenStream2, TokenStream2) = (generate_cfg_str_slice(&added), generate_cfg_str_slice(&removed));
+        boundaries.push(quote! { (#from, #to, #added, #removed) });
+    }
 
+    // Serialize it as a constant
+    let name: Ident = format_ident!("{}_FIELD_DIFF", to_upper_snake_case(&ident.to_string()));
+    let doc: String =
+        format!("Lists for every pair of consecutive versions which fields of [`{ident}`] are added and removed, as `(from, to, added, removed)`.");
+    Ok(quote! {
+        #[doc = #doc]
+        #vis const #name: &[(&str, &str, &[&str], &[&str])] = &[#(#boundaries),*];
+    })
+}
 
-/// Filters the given attributes minus the `#[version(...)]`-attribute and compiles it to a [`TokenStream2`].
+/// Generates a constant listing the names of the fields of a struct in a particular version.
 ///
 /// # Arguments
-/// - `attrs`: The list of [`Attribute`]s to filter.
+/// - `vis`: The [`Visibility`] of the struct, which is also used for the constant.
+/// - `ident`: The identifier of the struct.
+/// - `kept`: The [`Field`]s that survived filtering for the current version, each with the cfg-predicate under which it
+///   exists (see [`field_in_version()`]). Unnamed fields are named after their position in the generated struct.
 ///
 /// # Returns
-/// A new [`TokenStream2`] that encodes the body item but without certain components if filtered out by the version.
-fn generate_attrs(attrs: &[Attribute]) -> TokenStream2 {
-    // Serialize them all, except the ones we don't like
-    let mut stream: TokenStream2 = TokenStream2::new();
-    for attr in attrs {
-        // See if it's a match
-        if !attr.path().is_ident("version") {
-            stream.extend(quote! { #attr });
-        }
+/// A new [`TokenStream2`] that encodes a `<STRUCT>_FIELDS: &[&str]`-constant.
+///
+/// # Errors
+/// This function errors if a field of a tuple struct only exists under a cfg-predicate, as we can't tell the other fields'
+/// positions.
+fn generate_field_names(vis: &Visibility, ident: &Ident, kept: &[(&Field, Option<TokenStream2>)]) -> Result<TokenStream2, Diagnostic> {
+    if let Some((field, _)) = kept.iter().find(|(f, pred)| f.ident.is_none() && pred.is_some()) {
+        return Err(Diagnostic::spanned(
+            field.ty.span(),
+            Level::Error,
+            format!("Cannot list the fields of tuple struct '{ident}' with fields that only exist under a `cfg_attr`"),
+        ));
     }
-    stream
+    let names: Vec<(String, Option<TokenStream2>)> = kept
+        .iter()
+        .enumerate()
+        .map(|(i, (f, pred))| (f.ident.as_ref().map(Ident::to_string).unwrap_or_else(|| i.to_string()), pred.clone()))
+        .collect();
+    let names: TokenStream2 = generate_cfg_str_slice(&names);
+    let name: Ident = format_ident!("{}_FIELDS", to_upper_snake_case(&ident.to_string()));
+    let doc: String = format!("Lists the names of the fields of [`{ident}`] in this version.");
+    Ok(quote! {
+        #[doc = #doc]
+        #vis const #name: &[&str] = #names;
+    })
 }
 
-/// Filters the given field in accordance to the list of versions and compiles it to a [`TokenStream2`].
+/// Generates a compile-time assertion on the size of a struct or enum in a particular version.
 ///
 /// # Arguments
-/// - `field`: The [`Field`] to filter.
+/// - `attrs`: The attributes of the struct or enum, which may contain `#[version_size(...)]`.
+/// - `ident`: The identifier of the struct or enum.
+/// - `generics`: The [`Generics`] of the struct or enum, which may not have any type or const parameters.
 /// - `versions`: The list of versions in total (allows us to define order)
-/// - `version`: The current version to filter for.
+/// - `version`: The current version to generate the assertion for.
 ///
 /// # Returns
-/// A new [`TokenStream2`] that encodes the body item but without certain components if filtered out by the version.
-fn generate_filtered_field(field: &Field, versions: &VersionList, version: &Version) -> Result<Option<TokenStream2>, Diagnostic> {
-    // First, check the item's attributes to see if it has been version filtered
-    if let Some(filter) = get_version_attr(&field.attrs)? {
-        // Next, see if this matches the current version
-        filter.verify(versions)?;
-        if !filter.matches(versions, version) {
-            // Filtered oot!
-            return Ok(None);
-        }
+/// A new [`TokenStream2`] that encodes the assertion, which is empty if no `#[version_size(...)]` matches the version.
+///
+/// # Errors
+/// This function errors if any of the `#[version_size(...)]`-attributes was invalid, or if one matches but the struct or enum
+/// is generic over types or constants (as its size then depends on what it's instantiated with).
+fn generate_size_assert(
+    attrs: &[Attribute],
+    ident: &Ident,
+    generics: &Generics,
+    versions: &VersionList,
+    version: &Version,
+) -> Result<TokenStream2, Diagnostic> {
+    let size: Expr = match get_version_override::<Expr>(attrs, "version_size", versions, version)? {
+        Some(size) => size,
+        None => return Ok(TokenStream2::new()),
+    };
+    if let Some(param) = generics.params.iter().find(|param| !matches!(param, GenericParam::Lifetime(_))) {
+        return Err(Diagnostic::spanned(
+            param.span(),
+            Level::Error,
+            format!("Cannot assert the size of '{ident}' with `#[version_size(...)]`, as it is generic over types or constants"),
+        ));
     }
-
-    // Otherwise, serialize with adapted attributes
-    let Field { attrs, vis, mutability: _, ident, colon_token, ty } = field;
-    let mut stream: TokenStream2 = generate_attrs(attrs);
-    stream.extend(quote! { #vis #ident #colon_token #ty });
-    Ok(Some(stream))
+    let msg: String = format!("Size of '{ident}' in version '{}' does not match its `#[version_size(...)]`", version.0);
+    Ok(quote_spanned! { size.span() =>
+        const _: () = ::core::assert!(::core::mem::size_of::<#ident>() == #size, #msg);
+    })
 }
 
-/// Filters the given enum variant in accordance to the list of versions and compiles it to a [`TokenStream2`].
+/// Generates a `#[cfg(test)]`-module with basic tests for a struct in a particular version.
+///
+/// The tests assert that the struct is well-formed, and, if the `default` and `builder` options are given, that it can be
+/// constructed through those. The struct is assumed not to be generic, as we wouldn't know what to instantiate it with.
 ///
 /// # Arguments
-/// - `variant`: The [`Variant`] to filter.
+/// - `ident`: The identifier of the struct.
+/// - `fields`: The [`Fields`] of the struct, used to decide whether a builder is generated.
+/// - `kept`: The [`Field`]s that survived filtering for the current version, each with the cfg-predicate under which it
+///   exists (see [`field_in_version()`]).
 /// - `versions`: The list of versions in total (allows us to define order)
-/// - `version`: The current version to filter for.
+/// - `version`: The current version to generate the tests for.
+/// - `opts`: The [`Options`] that determine which tests to generate.
 ///
 /// # Returns
-/// A new [`TokenStream2`] that encodes the body item but without certain components if filtered out by the version.
-fn generate_filtered_variant(variant: &Variant, versions: &VersionList, version: &Version) -> Result<Option<TokenStream2>, Diagnostic> {
-    // First, check the item's attributes to see if it has been version filtered
-    if let Some(filter) = get_version_attr(&variant.attrs)? {
-        // Next, see if this matches the current version
-        filter.verify(versions)?;
-        if !filter.matches(versions, version) {
-            // Filtered oot!
-            return Ok(None);
-        }
+/// A new [`TokenStream2`] that encodes a `<struct>_versioning_tests`-module.
+///
+/// # Errors
+/// This function errors if any of the `#[version_default_value(...)]`-attributes was invalid.
+fn generate_tests(
+    ident: &Ident,
+    fields: &Fields,
+    kept: &[(&Field, Option<TokenStream2>)],
+    versions: &VersionList,
+    version: &Version,
+    opts: &Options,
+) -> Result<TokenStream2, Diagnostic> {
+    // Collect the tests
+    let mut tests: TokenStream2 = quote! {
+        #[test]
+        fn is_well_formed() { let _ = ::core::mem::size_of::<super::#ident>(); }
+    };
+    if opts.default {
+        tests.extend(quote! {
+            #[test]
+            fn default_constructs() { let _: super::#ident = ::core::default::Default::default(); }
+        });
     }
-
-    // Serialize the attributes as first part of the module
-    let Variant { attrs, ident, fields, discriminant } = variant;
-    let mut stream: TokenStream2 = generate_attrs(attrs);
-    stream.extend(quote! { #ident });
-    // Serialize the fields, if any
-    match fields {
-        Fields::Named(named) => {
-            // Serialize the field as a whole
-            let FieldsNamed { named, brace_token } = named;
-            let mut children: TokenStream2 = TokenStream2::new();
-            for pair in named.pairs() {
-                // Only add filtered ones too
-                let (field, comma): (&Field, Option<&Comma>) = pair.into_tuple();
-                if let Some(stream) = generate_filtered_field(field, versions, version)? {
-                    children.extend(stream);
-                    children.extend(quote! { #comma });
-                }
-            }
-            // Add the whole thing
-            brace_token.surround(&mut stream, |stream: &mut TokenStream2| stream.extend(children));
-        },
-        Fields::Unnamed(unnamed) => {
-            // Serialize the field as a whole
-            let FieldsUnnamed { unnamed, paren_token } = unnamed;
-            let mut children: TokenStream2 = TokenStream2::new();
-            for pair in unnamed.pairs() {
-                // Only add filtered ones too
-                let (field, comma): (&Field, Option<&Comma>) = pair.into_tuple();
-                if let Some(stream) = generate_filtered_field(field, versions, version)? {
-                    children.extend(stream);
-                    children.extend(quote! { #comma });
+    if opts.builder && matches!(fields, Fields::Named(_)) {
+        let builder: Ident = format_ident!("{ident}Builder");
+        tests.extend(quote! {
+            #[test]
+            fn builder_constructs() { let _: super::#builder = super::#builder::new(); }
+        });
+        // If we know a value for every field, we can also build it
+        if opts.default {
+            let idents: Vec<&Option<Ident>> = kept.iter().map(|(f, _)| &f.ident).collect();
+            let cfgs = kept.iter().map(|(_, pred)| pred.as_ref().map(|pred| quote! { #[cfg(#pred)] }).unwrap_or_default());
+            let values: Vec<TokenStream2> = get_default_values(kept, versions, version)?;
+            let msg: String = format!("Failed to build '{ident}' with every field set");
+            tests.extend(quote! {
+                #[test]
+                fn builder_builds() {
+                    #[allow(unused_imports)]
+                    use super::*;
+                    let builder: super::#builder = super::#builder::new();
+                    #(#cfgs let builder: super::#builder = builder.#idents(#values);)*
+                    ::std::assert!(builder.build().is_ok(), #msg);
                 }
-            }
-            // Add the whole thing
-            paren_token.surround(&mut stream, |stream: &mut TokenStream2| stream.extend(children));
-        },
-        Fields::Unit => {},
-    }
-    // Serialize the discriminant, if any
-    if let Some((eq, expr)) = discriminant {
-        stream.extend(quote! { #eq #expr });
+            });
+        }
     }
 
-    // Done!
-    Ok(Some(stream))
+    // Wrap them in a module
+    let name: Ident = format_ident!("{}_versioning_tests", to_upper_snake_case(&ident.to_string()).to_lowercase());
+    Ok(quote! {
+        #[cfg(test)]
+        mod #name {
+            #tests
+        }
+    })
 }
 
-/// Filters the given trait item in accordance to the list of versions and compiles it to a [`TokenStream2`].
+/// Generates the expression that converts one version of a struct into another.
+///
+/// Fields that exist in both versions are moved over, and fields that only exist in the `to`-version are set to their
+/// [`Default`]. Any fields that only exist in the `from`-version are dropped. Fields with a `#[version_convert(...)]`-closure
+/// are computed by it instead, which is called with a reference to `value` before anything is moved out of it.
+///
+/// Fields that exist in both versions with a different type are converted with [`Into`]. If the types aren't compatible, this
+/// errors at the field's type when compiling the generated code, in which case a `#[version_convert(...)]` is needed.
+///
+/// Fields that only exist under a cfg-predicate are only initialized if it holds, and are only moved over if they exist in the
+/// `from`-version too (or else set to their [`Default`]).
 ///
 /// # Arguments
-/// - `item`: The [`TraitItem`] to filter.
-/// - `versions`: The list of versions in total (allows us to define order)
-/// - `version`: The current version to filter for.
+/// - `from`: The [`ConversionShape`] of the struct to convert from, which is assumed to be in a variable called `value`.
+/// - `to`: The [`ConversionShape`] of the struct to convert to.
+/// - `strict`: If true, does not convert fields of which the type changed.
 ///
 /// # Returns
-/// A new [`TokenStream2`] that encodes the body item but without certain components if filtered out by the version.
-fn generate_filtered_trait_item(item: &TraitItem, versions: &VersionList, version: &Version) -> Result<Option<TokenStream2>, Diagnostic> {
-    // First, check the item's attributes to see if it has been version filtered
-    if let Some(attrs) = trait_item_attrs(item) {
-        if let Some(filter) = get_version_attr(attrs)? {
-            // Next, see if this matches the current version
-            filter.verify(versions)?;
-            if !filter.matches(versions, version) {
-                // Filtered oot!
-                return Ok(None);
-            }
+/// An expression constructing the `to`-version, or [`None`] if `strict` is given and some field exists in both versions with a
+/// different type.
+fn generate_conversion_step(from: &ConversionShape, to: &ConversionShape, strict: bool) -> Option<TokenStream2> {
+    let mut computed: Vec<TokenStream2> = vec![];
+    let mut inits: Vec<TokenStream2> = Vec::with_capacity(to.fields.len());
+    for ConversionField { ident, ty, convert, pred } in &to.fields {
+        let cfg = |pred: Option<TokenStream2>| pred.map(|pred| quote! { #[cfg(#pred)] }).unwrap_or_default();
+        if let Some(convert) = convert {
+            let var: Ident = format_ident!("__convert_{}", ident);
+            let cfg: TokenStream2 = cfg(pred.clone());
+            computed.push(quote! { #cfg let #var = convert(&value, #convert); });
+            inits.push(quote! { #cfg #ident: #var });
+            continue;
+        }
+        let (moved, exists): (TokenStream2, &Option<TokenStream2>) = match from.fields.iter().find(|other| other.ident == *ident) {
+            Some(other) if other.ty == *ty => (quote! { #ident: value.#ident }, &other.pred),
+            Some(_) if strict => return None,
+            Some(other) => (quote_spanned! { ty.span() => #ident: ::std::convert::Into::into(value.#ident) }, &other.pred),
+            None => {
+                let cfg: TokenStream2 = cfg(pred.clone());
+                inits.push(quote! { #cfg #ident: ::std::default::Default::default() });
+                continue;
+            },
+        };
+        // If the source field may not exist, default it in that case
+        match exists {
+            Some(exists) => {
+                let (exists, missing): (TokenStream2, TokenStream2) = match pred {
+                    Some(pred) => (quote! { all(#pred, #exists) }, quote! { all(#pred, not(#exists)) }),
+                    None => (quote! { #exists }, quote! { not(#exists) }),
+                };
+                inits.push(quote! { #[cfg(#exists)] #moved });
+                inits.push(quote! { #[cfg(#missing)] #ident: ::std::default::Default::default() });
+            },
+            None => {
+                let cfg: TokenStream2 = cfg(pred.clone());
+                inits.push(quote! { #cfg #moved });
+            },
         }
     }
+    let path: &TokenStream2 = &to.path;
+    if computed.is_empty() {
+        return Some(quote! { #path { #(#inits,)* } });
+    }
 
-    // Match after all (third time we're writing this) to filter oot some attributes
-    match item {
-        TraitItem::Const(TraitItemConst { attrs, const_token, ident, generics, colon_token, ty, default, semi_token }) => {
-            let mut stream: TokenStream2 = generate_attrs(attrs);
-            stream.extend(quote! { #const_token #ident #generics #colon_token #ty });
-            if let Some((eq_token, expr)) = default {
-                stream.extend(quote! { #eq_token #expr })
+    // Bind the closures to the source struct through a function, which lets the compiler infer their argument type
+    Some(quote! {
+        {
+            #[inline]
+            fn convert<S, T>(value: &S, f: impl ::std::ops::FnOnce(&S) -> T) -> T { f(value) }
+            #(#computed)*
+            #path { #(#inits,)* }
+        }
+    })
+}
+
+/// Generates the body of a conversion between two versions of a struct by composing it through other versions.
+///
+/// This looks for the shortest chain of versions between which no field changes type (see [`generate_conversion_step()`]).
+///
+/// # Arguments
+/// - `shapes`: The [`ConversionShape`]s of the struct for every version, or [`None`] where it's filtered out.
+/// - `from`: The index of the version to convert from.
+/// - `to`: The index of the version to convert to.
+///
+/// # Returns
+/// The statements performing every step of the chain, or [`None`] if there is no such chain.
+fn generate_conversion_chain(shapes: &[Option<ConversionShape>], from: usize, to: usize) -> Option<TokenStream2> {
+    // Do a breadth-first search to find the shortest chain
+    let mut prev: Vec<Option<usize>> = vec![None; shapes.len()];
+    let mut todo: VecDeque<usize> = VecDeque::from([from]);
+    while let Some(i) = todo.pop_front() {
+        if i == to {
+            break;
+        }
+        for j in 0..shapes.len() {
+            if j == from || prev[j].is_some() {
+                continue;
             }
-            stream.extend(quote! { #semi_token });
-            Ok(Some(stream))
-        },
-        TraitItem::Fn(TraitItemFn { attrs, sig, default, semi_token }) => {
-            let mut stream: TokenStream2 = generate_attrs(attrs);
-            stream.extend(quote! { #sig #default #semi_token });
-            Ok(Some(stream))
-        },
-        TraitItem::Macro(TraitItemMacro { attrs, mac, semi_token }) => {
-            let mut stream: TokenStream2 = generate_attrs(attrs);
-            stream.extend(quote! { #mac #semi_token });
-            Ok(Some(stream))
-        },
-        TraitItem::Type(TraitItemType { attrs, type_token, ident, generics, colon_token, bounds, default, semi_token }) => {
-            let mut stream: TokenStream2 = generate_attrs(attrs);
-            stream.extend(quote! { #type_token #ident #generics #colon_token #bounds });
-            if let Some((eq_token, expr)) = default {
-                stream.extend(quote! { #eq_token #expr })
+            if let (Some(lhs), Some(rhs)) = (&shapes[i], &shapes[j]) {
+                if generate_conversion_step(lhs, rhs, true).is_some() {
+                    prev[j] = Some(i);
+                    todo.push_back(j);
+                }
             }
-            stream.extend(quote! { #semi_token });
-            Ok(Some(stream))
-        },
-
-        // Vertabim is passed as-is
-        TraitItem::Verbatim(v) => Ok(Some(quote! { #v })),
+        }
+    }
 
-        // And any others, 'cuz non-exhaustive ;(
-        other => panic!("Encountered unknown TraitItem variant '{other:?}'"),
+    // Walk it back to serialize the steps
+    let mut chain: Vec<usize> = vec![to];
+    while let Some(i) = prev[*chain.last()?] {
+        chain.push(i);
+    }
+    if chain.last() != Some(&from) {
+        return None;
     }
+    let mut body: TokenStream2 = TokenStream2::new();
+    for step in chain.windows(2).rev() {
+        let (lhs, rhs): (&ConversionShape, &ConversionShape) = (shapes[step[1]].as_ref()?, shapes[step[0]].as_ref()?);
+        let expr: TokenStream2 = generate_conversion_step(lhs, rhs, true)?;
+        let path: &TokenStream2 = &rhs.path;
+        body.extend(quote! { let value: #path = #expr; });
+    }
+    body.extend(quote! { value });
+    Some(body)
 }
 
-/// Filters the given foreign item in accordance to the list of versions and compiles it to a [`TokenStream2`].
+/// Generates [`From`]-implementations between the versions of the structs in the given item.
+///
+/// These are generated for the item itself if it's a struct, or else for the structs directly in it if it's a module. Only
+/// (non-generic) structs with named fields are considered, and their fields must be visible from the scope of the
+/// `#[versioning(...)]`-macro (e.g., `pub(super)`).
+///
+/// Fields of which the type differs between a pair are converted with [`Into`], unless `compose_conversions` is given and
+/// there is a chain of versions in between in which no field changes type.
 ///
 /// # Arguments
-/// - `item`: The [`ForeignItem`] to filter.
+/// - `item`: The [`Item`] given to the `#[versioning(...)]`-macro.
 /// - `versions`: The list of versions in total (allows us to define order)
-/// - `version`: The current version to filter for.
+/// - `groups`: The (groups of coalesced) versions that are generated, each with the predicate that gates them if any.
+/// - `opts`: The [`Options`] that determine which conversions to generate.
 ///
 /// # Returns
-/// A new [`TokenStream2`] that encodes the body item but without certain components if filtered out by the version.
-fn generate_filtered_foreign_item(item: &ForeignItem, versions: &VersionList, version: &Version) -> Result<Option<TokenStream2>, Diagnostic> {
-    // First, check the item's attributes to see if it has been version filtered
-    if let Some(attrs) = foreign_item_attrs(item) {
-        if let Some(filter) = get_version_attr(attrs)? {
-            // Next, see if this matches the current version
-            filter.verify(versions)?;
-            if !filter.matches(versions, version) {
-                // Filtered oot!
-                return Ok(None);
-            }
+/// A new [`TokenStream2`] that encodes the implementations.
+///
+/// # Errors
+/// This function errors if any of the attributes of the structs were invalid, or if an explicitly given pair refers to a
+/// version in which a struct is filtered out.
+fn generate_conversions(
+    item: &Item,
+    versions: &VersionList,
+    groups: &[(Vec<&Version>, Option<TokenStream2>)],
+    opts: &Options,
+) -> Result<TokenStream2, Diagnostic> {
+    // Find the (groups of) versions to convert between, and whether they were asked for explicitly
+    let mut pairs: Vec<(usize, usize, Option<Span>)> = vec![];
+    if opts.conversions {
+        for i in 1..groups.len() {
+            pairs.push((i - 1, i, None));
+            pairs.push((i, i - 1, None));
         }
     }
-
-    // Also needs to be manually matches to skip attributes
-    match item {
-        ForeignItem::Fn(ForeignItemFn { attrs, vis, sig, semi_token }) => {
-            let mut stream: TokenStream2 = generate_attrs(attrs);
-            stream.extend(quote! { #vis #sig #semi_token });
-            Ok(Some(stream))
-        },
-        ForeignItem::Macro(ForeignItemMacro { attrs, mac, semi_token }) => {
-            let mut stream: TokenStream2 = generate_attrs(attrs);
-            stream.extend(quote! { #mac #semi_token });
-            Ok(Some(stream))
-        },
-        ForeignItem::Static(ForeignItemStatic { attrs, vis, static_token, mutability, ident, colon_token, ty, semi_token }) => {
-            let mut stream: TokenStream2 = generate_attrs(attrs);
-            stream.extend(quote! { #vis #static_token #mutability #ident #colon_token #ty #semi_token });
-            Ok(Some(stream))
+    match &opts.conversion_pairs {
+        Some(ConversionPairs::All) => {
+            for i in 0..groups.len() {
+                for j in (0..groups.len()).filter(|j| *j != i) {
+                    if !pairs.iter().any(|(from, to, _)| (*from, *to) == (i, j)) {
+                        pairs.push((i, j, None));
+                    }
+                }
+            }
         },
-        ForeignItem::Type(ForeignItemType { attrs, vis, type_token, ident, generics, semi_token }) => {
-            let mut stream: TokenStream2 = generate_attrs(attrs);
-            stream.extend(quote! { #vis #type_token #ident #generics #semi_token });
-            Ok(Some(stream))
+        Some(ConversionPairs::Only(only, span)) => {
+            for (from, to) in only {
+                let mut indices: [usize; 2] = [0; 2];
+                for (index, version) in indices.iter_mut().zip([&versions.0[*from], &versions.0[*to]]) {
+                    *index = match groups.iter().position(|(group, _)| group.iter().any(|v| std::ptr::eq(*v, version))) {
+                        Some(index) => index,
+                        None => {
+                            return Err(Diagnostic::spanned(
+                                *span,
+                                Level::Error,
+                                format!("Cannot generate conversions for version '{}', as it is filtered out", version.0),
+                            ));
+                        },
+                    };
+                }
+                // Coalesced versions are the same types, which `std` already converts
+                if indices[0] == indices[1] {
+                    continue;
+                }
+                pairs.retain(|(from, to, _)| (*from, *to) != (indices[0], indices[1]));
+                pairs.push((indices[0], indices[1], Some(*span)));
+            }
         },
+        None => {},
+    }
+    if pairs.is_empty() {
+        return Ok(TokenStream2::new());
+    }
+
+    // Find the structs to convert
+    let (structs, toplevel, prefix): (Vec<&ItemStruct>, bool, Option<&Ident>) = match item {
+        Item::Struct(item) => (vec![item], true, None),
+        Item::Mod(ItemMod { ident, content: Some((_, items)), .. }) => (
+            items.iter().filter_map(|item| if let Item::Struct(item) = item { Some(item) } else { None }).collect(),
+            false,
+            if opts.nest_toplevel_modules { Some(ident) } else { None },
+        ),
+        _ => return Ok(TokenStream2::new()),
+    };
+
+    // Generate the conversions for each of them
+    let mut stream: TokenStream2 = TokenStream2::new();
+    for strukt in structs {
+        if !matches!(strukt.fields, Fields::Named(_))
+            || !strukt.generics.params.is_empty()
+            || strukt.attrs.iter().any(|attr| attr.path().segments.last().map(|s| s.ident == "versioning").unwrap_or(false))
+        {
+            continue;
+        }
+
+        // Any conditions on the struct itself also apply to its conversions
+        let mut cfgs: Vec<TokenStream2> = vec![];
+        for attr in &strukt.attrs {
+            if attr.path().is_ident("cfg") {
+                if let Meta::List(list) = &attr.meta {
+                    cfgs.push(list.tokens.clone());
+                }
+            } else if attr.path().is_ident("version_feature") {
+                if let Ok(feature) = attr.parse_args::<LitStr>() {
+                    cfgs.push(quote! { feature = #feature });
+                }
+            }
+        }
+
+        // Find what the struct looks like in every version
+        let mut shapes: Vec<Option<ConversionShape>> = Vec::with_capacity(groups.len());
+        'groups: for (group, _) in groups {
+            let version: &Version = group[0];
+            if let Some(filter) = get_version_attr(&strukt.attrs, opts)? {
+                filter.verify(versions)?;
+                if !filter.matches(versions, version) {
+                    shapes.push(None);
+                    continue 'groups;
+                }
+            }
+            let (ident, _): (Cow<Ident>, TokenStream2) = resolve_ident(&strukt.attrs, &strukt.ident, versions, version, toplevel, opts)?;
+            let module: Ident = opts.module_ident(version);
+            let path: TokenStream2 = match prefix {
+                Some(prefix) => quote! { self::#module::#prefix::#ident },
+                None => quote! { self::#module::#ident },
+            };
+            let mut fields: Vec<ConversionField> = Vec::with_capacity(strukt.fields.len());
+            for field in &strukt.fields {
+                let pred: Option<TokenStream2> = match field_in_version(field, versions, version, opts)? {
+                    Some(pred) => pred,
+                    None => continue,
+                };
+                if let Some(ident) = &field.ident {
+                    let ty: Cow<Type> = resolve_type(&field.attrs, &field.ty, versions, version)?;
+                    let convert: Option<Expr> = get_version_override::<Expr>(&field.attrs, "version_convert", versions, version)?;
+                    fields.push(ConversionField { ident, ty, convert, pred });
+                }
+            }
+            shapes.push(Some(ConversionShape { path, fields }));
+        }
+
+        // Generate the pairs
+        let mut generated: Vec<(usize, usize, TokenStream2)> = Vec::with_capacity(pairs.len());
+        for (from, to, span) in &pairs {
+            let name: String = strukt.ident.to_string();
+            let (lhs, rhs): (&ConversionShape, &ConversionShape) = match (&shapes[*from], &shapes[*to]) {
+                (Some(lhs), Some(rhs)) => (lhs, rhs),
+                _ => match span {
+                    Some(span) => {
+                        return Err(Diagnostic::spanned(
+                            *span,
+                            Level::Error,
+                            format!(
+                                "Cannot convert struct '{name}' from version '{}' to '{}', as it is filtered out in at least one of them",
+                                groups[*from].0[0].0, groups[*to].0[0].0
+                            ),
+                        ));
+                    },
+                    None => continue,
+                },
+            };
 
-        // Vertabim is passed as-is
-        ForeignItem::Verbatim(v) => Ok(Some(quote! { #v })),
+            // Convert directly if no types change, or else through a chain of other versions or using `Into`
+            let body: TokenStream2 = match generate_conversion_step(lhs, rhs, true) {
+                Some(expr) => expr,
+                None => match opts.compose_conversions.then(|| generate_conversion_chain(&shapes, *from, *to)).flatten() {
+                    Some(body) => body,
+                    None => match generate_conversion_step(lhs, rhs, false) {
+                        Some(expr) => expr,
+                        None => continue,
+                    },
+                },
+            };
 
-        // And any others, 'cuz non-exhaustive ;(
-        other => panic!("Encountered unknown ForeignItem variant '{other:?}'"),
+            // Serialize the implementation, gated on both versions
+            let mut preds: Vec<&TokenStream2> = vec![];
+            preds.extend(groups[*from].1.as_ref());
+            preds.extend(groups[*to].1.as_ref());
+            preds.extend(cfgs.iter());
+            let gate: TokenStream2 = if preds.is_empty() {
+                TokenStream2::new()
+            } else {
+                quote! { #[cfg(all(#(#preds),*))] }
+            };
+            let (lhs, rhs): (&TokenStream2, &TokenStream2) = (&lhs.path, &rhs.path);
+            stream.extend(quote! {
+                #gate
+                impl ::std::convert::From<#lhs> for #rhs {
+                    #[inline]
+                    #[allow(unused_variables)]
+                    fn from(value: #lhs) -> Self { #body }
+                }
+            });
+            generated.push((*from, *to, gate));
+        }
+
+        // Test that converting there and back again preserves the fields that exist on both sides, if told to do so
+        if opts.gen_tests && (opts.default || derives(&strukt.attrs, "Default")) && derives(&strukt.attrs, "PartialEq") {
+            stream.extend(generate_round_trip_tests(&strukt.ident, groups, &shapes, &generated));
+        }
     }
+    Ok(stream)
 }
 
-/// Filters the given impl item in accordance to the list of versions and compiles it to a [`TokenStream2`].
+/// Generates a `#[cfg(test)]`-module with tests that convert a struct to another version and back.
+///
+/// A test is generated for every pair of versions that can be converted both ways, which asserts that the fields that exist in
+/// both of them are preserved when starting from a [`Default`] value. Fields that only exist under a cfg-predicate (see
+/// [`field_in_version()`]) are not checked.
+///
+/// As such, these tests are only scaffolding: a conversion that drops a field still passes if the field's default is the
+/// value it's reset to. The generated [`Default`]-implementation (see `default`) uses `#[version_default_value(...)]`, so
+/// giving fields a non-default value there makes the tests meaningful.
 ///
 /// # Arguments
-/// - `item`: The [`ImplItem`] to filter.
-/// - `versions`: The list of versions in total (allows us to define order)
-/// - `version`: The current version to filter for.
+/// - `ident`: The identifier of the struct.
+/// - `groups`: The (groups of coalesced) versions that are generated.
+/// - `shapes`: The [`ConversionShape`]s of the struct for every version, or [`None`] where it's filtered out.
+/// - `generated`: The `(from, to)`-pairs of the conversions that are generated, each with the cfg-attribute gating it.
 ///
 /// # Returns
-/// A new [`TokenStream2`] that encodes the body item but without certain components if filtered out by the version.
-fn generate_filtered_impl_item(item: &ImplItem, versions: &VersionList, version: &Version) -> Result<Option<TokenStream2>, Diagnostic> {
-    // First, check the item's attributes to see if it has been version filtered
-    if let Some(attrs) = impl_item_attrs(item) {
-        if let Some(filter) = get_version_attr(attrs)? {
-            // Next, see if this matches the current version
-            filter.verify(versions)?;
-            if !filter.matches(versions, version) {
-                // Filtered oot!
-                return Ok(None);
+/// A new [`TokenStream2`] that encodes a `<struct>_conversion_tests`-module, or nothing if no pair converts both ways.
+fn generate_round_trip_tests(
+    ident: &Ident,
+    groups: &[(Vec<&Version>, Option<TokenStream2>)],
+    shapes: &[Option<ConversionShape>],
+    generated: &[(usize, usize, TokenStream2)],
+) -> TokenStream2 {
+    let mut tests: TokenStream2 = TokenStream2::new();
+    for (from, to, gate) in generated {
+        if from > to || !generated.iter().any(|(f, t, _)| (f, t) == (to, from)) {
+            continue;
+        }
+        let (lhs, rhs): (&ConversionShape, &ConversionShape) = match (&shapes[*from], &shapes[*to]) {
+            (Some(lhs), Some(rhs)) => (lhs, rhs),
+            _ => continue,
+        };
+
+        // Find the fields to check
+        let shared: Vec<&Ident> = lhs
+            .fields
+            .iter()
+            .filter(|field| field.pred.is_none() && rhs.fields.iter().any(|other| other.ident == field.ident && other.pred.is_none()))
+            .map(|field| field.ident)
+            .collect();
+        let (from, to): (&Ident, &Ident) = (&groups[*from].0[0].0, &groups[*to].0[0].0);
+        let msgs: Vec<String> =
+            shared.iter().map(|field| format!("Field '{field}' of '{ident}' changed in a round-trip from version '{from}' to '{to}'")).collect();
+
+        // Serialize the test
+        let test: Ident = format_ident!("{}_to_{}_round_trip", from.to_string().to_lowercase(), to.to_string().to_lowercase());
+        let (lhs, rhs): (&TokenStream2, &TokenStream2) = (&lhs.path, &rhs.path);
+        tests.extend(quote! {
+            #gate
+            #[test]
+            #[allow(unused_variables)]
+            fn #test() {
+                let value: #lhs = ::std::default::Default::default();
+                let back: #lhs = <#rhs as ::std::convert::From<#lhs>>::from(::std::default::Default::default()).into();
+                #(::std::assert!(value.#shared == back.#shared, #msgs);)*
             }
+        });
+    }
+    if tests.is_empty() {
+        return tests;
+    }
+
+    // Wrap them in a module
+    let name: Ident = format_ident!("{}_conversion_tests", to_upper_snake_case(&ident.to_string()).to_lowercase());
+    quote! {
+        #[cfg(test)]
+        mod #name {
+            use super::*;
+
+            #tests
         }
     }
+}
 
-    // Also needs to be manually matches to skip attributes
-    match item {
-        ImplItem::Const(ImplItemConst { attrs, vis, defaultness, const_token, ident, generics, colon_token, ty, eq_token, expr, semi_token }) => {
-            let mut stream: TokenStream2 = generate_attrs(attrs);
-            stream.extend(quote! { #vis #defaultness #const_token #ident #generics #colon_token #ty #eq_token #expr #semi_token });
-            Ok(Some(stream))
-        },
-        ImplItem::Fn(ImplItemFn { attrs, vis, defaultness, sig, block }) => {
-            let mut stream: TokenStream2 = generate_attrs(attrs);
-            stream.extend(quote! { #vis #defaultness #sig #block });
-            Ok(Some(stream))
-        },
-        ImplItem::Macro(ImplItemMacro { attrs, mac, semi_token }) => {
-            let mut stream: TokenStream2 = generate_attrs(attrs);
-            stream.extend(quote! { #mac #semi_token });
-            Ok(Some(stream))
+/// Generates a function returning the JSON Schema of the toplevel item in a particular version.
+///
+/// For generic items, the function takes the same generics (e.g., `v1_0_0::schema::<u64>()`) and requires the item to
+/// implement `JsonSchema` with them.
+///
+/// # Arguments
+/// - `item`: The [`Item`] given to the `#[versioning(...)]`-macro, which must be a struct or an enum.
+/// - `versions`: The list of versions in total (allows us to define order)
+/// - `version`: The current version to generate the function for.
+/// - `opts`: The [`Options`] that determine the name of the item.
+///
+/// # Returns
+/// A new [`TokenStream2`] that encodes a `schema() -> schemars::schema::RootSchema`-function. As such, this assumes
+/// `schemars` 0.8.
+///
+/// # Errors
+/// This function errors if the item is not a struct or an enum, or if its `#[version_rename(...)]`- or
+/// `#[version_bound(...)]`-attributes were invalid.
+fn generate_schema_fn(item: &Item, versions: &VersionList, version: &Version, opts: &Options) -> Result<TokenStream2, Diagnostic> {
+    let (attrs, ident, vis, generics): (&[Attribute], &Ident, &Visibility, &Generics) = match item {
+        Item::Enum(ItemEnum { attrs, ident, vis, generics, .. }) | Item::Struct(ItemStruct { attrs, ident, vis, generics, .. }) => {
+            (attrs, ident, vis, generics)
         },
-        ImplItem::Type(ImplItemType { attrs, vis, defaultness, type_token, ident, generics, eq_token, ty, semi_token }) => {
-            let mut stream: TokenStream2 = generate_attrs(attrs);
-            stream.extend(quote! { #vis #defaultness #type_token #ident #generics #eq_token #ty #semi_token });
-            Ok(Some(stream))
+        item => {
+            return Err(Diagnostic::spanned(item.span(), Level::Error, "'schema_fn' option can only be used on a struct or an enum".into()));
         },
+    };
+    let (ident, _): (Cow<Ident>, TokenStream2) = resolve_ident(attrs, ident, versions, version, true, opts)?;
+    let vis: Cow<Visibility> = wrapper_vis(vis, opts.force_public);
 
-        // Vertabim is passed as-is
-        ImplItem::Verbatim(v) => Ok(Some(quote! { #v })),
-
-        // And any others, 'cuz non-exhaustive ;(
-        other => panic!("Encountered unknown ImplItem variant '{other:?}'"),
+    // Require the item to be a schema for whatever generics it's given
+    let mut generics: Generics = resolve_generics(attrs, generics, versions, version)?.into_owned();
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let ty: Type = syn::parse_quote! { #ident #ty_generics };
+    if !generics.params.is_empty() {
+        generics.make_where_clause().predicates.push(syn::parse_quote! { #ty: ::schemars::JsonSchema });
     }
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    let doc: String = format!("Returns the JSON Schema of [`{ident}`] in this version.");
+    Ok(quote_spanned! { ident.span() =>
+        #[doc = #doc]
+        #[inline]
+        #vis fn schema #impl_generics () -> ::schemars::schema::RootSchema #where_clause { ::schemars::schema_for!(#ty) }
+    })
 }
 
 /// Filters the given body item in accordance to the list of versions and compiles it to a [`TokenStream2`].
@@ -589,7 +3702,18 @@ fn generate_filtered_impl_item(item: &ImplItem, versions: &VersionList, version:
 /// - `versions`: The list of versions in total (allows us to define order)
 /// - `version`: The current version to filter for.
 /// - `toplevel`: Only true for the first depth of recursion.
-/// - `force_public`: If given, always writes a `pub` for his item (in case it's nested in a version module). Note that nested modules are always hardcoded to `false`.
+/// - `force_public`: If given, writes a `pub` for his item if it was private (in case it's nested in a version module).
+///   Restricted visibilities (e.g., `pub(crate)`) are kept as-is, and `use`s are an exception (see [`wrapped_use_vis()`]).
+///   Note that nested modules are always hardcoded to `false`.
+/// - `opts`: The [`Options`] given to the `#[versioning(...)]`-macro.
+///
+/// The `pub` written by `force_public` is spanned to the original visibility, which is the call site for private items. This
+/// keeps rustc from reporting the versions that are never used as dead code.
+///
+/// If the item has a nested `#[versioning(...)]`, then only the attributes _before_ it are interpreted (e.g., a
+/// `#[version(...)]` there filters it for the outer versions). The nested attribute itself and everything after it are left
+/// untouched, as they are expanded by the nested macro later with its own list of versions. For a nested module, this results
+/// in paths of the shape `<outer version>::<inner version>::...`.
 ///
 /// # Returns
 /// A new [`TokenStream2`] that encodes the body item but without certain components if filtered out by the version.
@@ -599,53 +3723,113 @@ fn generate_filtered_item(
     version: &Version,
     toplevel: bool,
     force_public: bool,
+    opts: &Options,
 ) -> Result<Option<TokenStream2>, Diagnostic> {
     // First, check the item's attributes to see if it has been version filtered
     if let Some(attrs) = item_attrs(item) {
-        if let Some(filter) = get_version_attr(attrs)? {
+        // Only the attributes before a nested `#[versioning(...)]` are ours; the rest (and the item itself) are for it
+        let nested: Option<usize> = attrs.iter().position(|attr| attr.path().segments.last().map(|s| s.ident == "versioning").unwrap_or(false));
+        let ours: &[Attribute] = &attrs[..nested.unwrap_or(attrs.len())];
+        let filter: Option<VersionFilter> = get_version_attr(ours, opts)?;
+        if filter.is_none() && !toplevel {
+            opts.require_version(item.span(), "Item".into())?;
+        }
+        if let Some(filter) = filter {
             // Next, see if this matches the current version
             filter.verify(versions)?;
+            check_dead(attrs, &filter, versions, item.span(), || "Item".into());
             if !filter.matches(versions, version) {
                 // Filtered oot!
                 return Ok(None);
             }
         }
+
+        // If it's nested, leave the item to the nested macro
+        if let Some(i) = nested {
+            let mut stream: TokenStream2 = generate_attrs(ours, versions, version)?;
+            let mut item: Item = item.clone();
+            if let Some(attrs) = item_attrs_mut(&mut item) {
+                attrs.drain(..i);
+            }
+            stream.extend(quote! { #item });
+            return Ok(Some(stream));
+        }
+
+        // Only items with a name of their own can be renamed (toplevel modules are named after the version instead)
+        if matches!(item, Item::ExternCrate(_) | Item::ForeignMod(_) | Item::Impl(_) | Item::Macro(_) | Item::Use(_))
+            || (toplevel && matches!(item, Item::Mod(_)) && !opts.nest_toplevel_modules)
+        {
+            if let Some(attr) = attrs.iter().find(|attr| attr.path().is_ident("version_rename")) {
+                return Err(Diagnostic::spanned(
+                    attr.path().span(),
+                    Level::Error,
+                    "`#[version_rename(...)]` can only be used on named items like structs, constants or functions (use \
+                     `#[version_extern_rename(...)]` for `extern crate`s and `#[version_module_name(...)]` for the toplevel module)"
+                        .into(),
+                ));
+            }
+        }
     }
 
     // Then recurse if necessary
     match item {
         // These all have some kind of recursion going on
         Item::Mod(ItemMod { attrs, vis, unsafety, mod_token, ident, content, semi }) => {
+            // Resolve the identifier, which is overridden is we _are_ toplevel but _not_ wrapping
+            let (ident, alias): (Cow<Ident>, TokenStream2) = if toplevel && !opts.nest_toplevel_modules {
+                (Cow::Owned(opts.module_ident(version)), TokenStream2::new())
+            } else {
+                resolve_ident(attrs, ident, versions, version, toplevel, opts)?
+            };
             // Serialize the attributes as first part of the module
-            let mut stream: TokenStream2 = generate_attrs(attrs);
+            let mut stream: TokenStream2 = generate_attrs(attrs, versions, version)?;
+            stream.extend(alias);
             // Serialize the visibility
-            if force_public {
-                let vis: Visibility = Visibility::Public(Pub { span: vis.span() });
-                stream.extend(quote! { #vis });
-            } else {
-                stream.extend(quote! { #vis });
-            }
+            let vis: Cow<Visibility> = wrapper_vis(vis, force_public);
+            stream.extend(quote! { #vis });
             // Serialize some other parts
             stream.extend(quote! {
                 #unsafety #mod_token
             });
-            // Serialize the indent, which is overridden is we _are_ toplevel but _not_ wrapping
-            if toplevel && !force_public {
-                let ident: &Ident = &version.0;
-                stream.extend(quote! { #ident });
-            } else {
-                stream.extend(quote! { #ident });
-            }
+            stream.extend(quote! { #ident });
             // Serialize content if there is any
             if let Some((brace, items)) = content {
+                // Remember which of the children are filtered out, so we can catch references to them (unless a sibling with the
+                // same name survives, e.g., because of `#[version(else)]`)
+                let n_filtered: usize = FILTERED_OUT.with(|filtered| filtered.borrow().len());
+                let mut filtered_out: Vec<&Ident> = Vec::new();
+                let mut kept: HashSet<&Ident> = HashSet::new();
+                for item in items {
+                    let (attrs, ident): (&[Attribute], &Ident) = match item {
+                        Item::Const(ItemConst { attrs, ident, .. }) | Item::Static(ItemStatic { attrs, ident, .. }) => (attrs, ident),
+                        Item::Fn(ItemFn { attrs, sig, .. }) => (attrs, &sig.ident),
+                        _ => continue,
+                    };
+                    match get_version_attr(attrs, opts)? {
+                        Some(filter) => {
+                            filter.verify(versions)?;
+                            if filter.matches(versions, version) {
+                                kept.insert(ident);
+                            } else {
+                                filtered_out.push(ident);
+                            }
+                        },
+                        None => {
+                            kept.insert(ident);
+                        },
+                    }
+                }
+                FILTERED_OUT.with(|filtered| filtered.borrow_mut().extend(filtered_out.into_iter().filter(|ident| !kept.contains(ident)).cloned()));
+
                 // Serialize all children
                 let mut children: TokenStream2 = TokenStream2::new();
                 for item in items {
                     // Only keep OK ones
-                    if let Some(stream) = generate_filtered_item(item, versions, version, false, false)? {
+                    if let Some(stream) = generate_filtered_item(item, versions, version, false, false, opts)? {
                         children.extend(stream);
                     }
                 }
+                FILTERED_OUT.with(|filtered| filtered.borrow_mut().truncate(n_filtered));
                 // Serialize them with braces
                 brace.surround(&mut stream, |stream: &mut TokenStream2| stream.extend(children));
             };
@@ -659,48 +3843,82 @@ fn generate_filtered_item(
         },
 
         Item::Enum(ItemEnum { attrs, vis, enum_token, ident, generics, brace_token, variants }) => {
-            // First, serialize the attributes
-            let mut stream: TokenStream2 = generate_attrs(attrs);
-            // Serialize the visibility
-            if force_public {
-                let vis: Visibility = Visibility::Public(Pub { span: vis.span() });
-                stream.extend(quote! { #vis });
-            } else {
-                stream.extend(quote! { #vis });
+            // First, serialize the attributes (and those due to renaming it, if any)
+            let (ident, alias): (Cow<Ident>, TokenStream2) = resolve_ident(attrs, ident, versions, version, toplevel, opts)?;
+            // Make it describe itself, if told to do so
+            let derives: Vec<Path> = if opts.schemars { vec![syn::parse_quote_spanned! { ident.span() => ::schemars::JsonSchema }] } else { vec![] };
+            let mut stream: TokenStream2 = generate_attrs_with_derives(attrs, &derives, versions, version)?;
+            stream.extend(alias);
+            // Mark it as non-exhaustive if this version may still evolve
+            if opts.is_non_exhaustive(versions, version) {
+                stream.extend(quote_spanned! { ident.span() => #[non_exhaustive] });
             }
+            // Serialize the visibility
+            let vis: Cow<Visibility> = wrapper_vis(vis, force_public);
+            stream.extend(quote! { #vis });
             // Serialize some other parts
+            let generics: Cow<Generics> = resolve_generics(attrs, generics, versions, version)?;
+            let where_clause: &Option<WhereClause> = &generics.where_clause;
             stream.extend(quote! {
-                #enum_token #ident #generics
+                #enum_token #ident #generics #where_clause
             });
             // Add the variants wrapped in braces
             let mut children: TokenStream2 = TokenStream2::new();
+            let mut n_kept: usize = 0;
             for pair in variants.pairs() {
                 let (variant, comma): (&Variant, Option<&Comma>) = pair.into_tuple();
-                if let Some(stream) = generate_filtered_variant(variant, versions, version)? {
+                if let Some(stream) = generate_filtered_variant(variant, versions, version, opts)? {
                     children.extend(stream);
                     children.extend(quote! { #comma });
+                    n_kept += 1;
                 }
             }
+            // An enum that lost all its variants is uninhabited, which is probably not what the user intended
+            if n_kept == 0 && !variants.is_empty() {
+                opts.warn(
+                    ident.span(),
+                    format!(
+                        "All variants of enum '{ident}' are filtered out in version '{}', making it uninhabited (consider filtering the enum as a \
+                         whole with `#[version(...)]` instead)",
+                        version.0
+                    ),
+                )?;
+            }
             brace_token.surround(&mut stream, |stream: &mut TokenStream2| stream.extend(children));
+            // Assert its size, if told to do so
+            if opts.size_asserts {
+                stream.extend(generate_size_assert(attrs, &ident, &generics, versions, version)?);
+            }
 
             // Done
             Ok(Some(stream))
         },
         Item::Struct(ItemStruct { attrs, vis, struct_token, ident, generics, fields, semi_token }) => {
-            // First, serialize the attributes
-            let mut stream: TokenStream2 = generate_attrs(attrs);
-            // Serialize the visibility
-            if force_public {
-                let vis: Visibility = Visibility::Public(Pub { span: vis.span() });
-                stream.extend(quote! { #vis });
-            } else {
-                stream.extend(quote! { #vis });
+            // First, serialize the attributes (and those due to renaming it, if any)
+            let (ident, alias): (Cow<Ident>, TokenStream2) = resolve_ident(attrs, ident, versions, version, toplevel, opts)?;
+            // Make it describe itself, if told to do so
+            let derives: Vec<Path> = if opts.schemars { vec![syn::parse_quote_spanned! { ident.span() => ::schemars::JsonSchema }] } else { vec![] };
+            let mut stream: TokenStream2 = generate_attrs_with_derives(attrs, &derives, versions, version)?;
+            stream.extend(alias);
+            // Mark it as non-exhaustive if this version may still evolve
+            if opts.is_non_exhaustive(versions, version) {
+                stream.extend(quote_spanned! { ident.span() => #[non_exhaustive] });
             }
-            // Serialize some other parts
+            // Serialize the visibility
+            let vis: Cow<Visibility> = wrapper_vis(vis, force_public);
+            stream.extend(quote! { #vis });
+            // Serialize some other parts (where tuple structs have their where-clause after the fields)
+            let generics: Cow<Generics> = resolve_generics(attrs, generics, versions, version)?;
+            let where_clause: &Option<WhereClause> = &generics.where_clause;
             stream.extend(quote! {
                 #struct_token #ident #generics
             });
+            if !matches!(fields, Fields::Unnamed(_)) {
+                stream.extend(quote! { #where_clause });
+            }
             // Serialize the fields, if any
+            let mut kept: Vec<(&Field, Option<TokenStream2>)> = Vec::with_capacity(fields.len());
+            let unit: bool = opts.unit_structs && !fields.is_empty();
             match fields {
                 Fields::Named(named) => {
                     // Serialize the field as a whole
@@ -709,13 +3927,18 @@ fn generate_filtered_item(
                     for pair in named.pairs() {
                         // Only add filtered ones too
                         let (field, comma): (&Field, Option<&Comma>) = pair.into_tuple();
-                        if let Some(stream) = generate_filtered_field(field, versions, version)? {
+                        if let Some(stream) = generate_filtered_field(field, versions, version, opts)? {
                             children.extend(stream);
                             children.extend(quote! { #comma });
+                            kept.push((field, field_in_version(field, versions, version, opts)?.flatten()));
                         }
                     }
-                    // Add the whole thing
-                    brace_token.surround(&mut stream, |stream: &mut TokenStream2| stream.extend(children));
+                    // Add the whole thing, unless nothing is left and we're told to collapse it
+                    if unit && kept.is_empty() {
+                        stream.extend(quote_spanned! { brace_token.span.close() => ; });
+                    } else {
+                        brace_token.surround(&mut stream, |stream: &mut TokenStream2| stream.extend(children));
+                    }
                 },
                 Fields::Unnamed(unnamed) => {
                     // Serialize the field as a whole
@@ -724,35 +3947,84 @@ fn generate_filtered_item(
                     for pair in unnamed.pairs() {
                         // Only add filtered ones too
                         let (field, comma): (&Field, Option<&Comma>) = pair.into_tuple();
-                        if let Some(stream) = generate_filtered_field(field, versions, version)? {
+                        if let Some(stream) = generate_filtered_field(field, versions, version, opts)? {
                             children.extend(stream);
                             children.extend(quote! { #comma });
+                            kept.push((field, field_in_version(field, versions, version, opts)?.flatten()));
                         }
                     }
-                    // Add the whole thing
-                    paren_token.surround(&mut stream, |stream: &mut TokenStream2| stream.extend(children));
+                    // Add the whole thing, unless nothing is left and we're told to collapse it (the semicolon is already there)
+                    if !unit || !kept.is_empty() {
+                        paren_token.surround(&mut stream, |stream: &mut TokenStream2| stream.extend(children));
+                    }
+                    stream.extend(quote! { #where_clause });
                 },
                 Fields::Unit => {},
             }
             // Generate the remaining
             stream.extend(quote! { #semi_token });
+            // Generate the builder alongside it, if told to do so
+            if opts.builder && matches!(fields, Fields::Named(_)) {
+                stream.extend(generate_builder(&vis, &ident, &generics, &kept, versions, version)?);
+            }
+            // Generate the default implementation, if told to do so
+            if opts.default {
+                stream.extend(generate_default(&ident, &generics, fields, &kept, unit && kept.is_empty(), versions, version)?);
+            }
+            // Generate the field matrix, if told to do so
+            if opts.field_matrix {
+                stream.extend(generate_field_matrix(&vis, &ident, fields, versions, opts)?);
+            }
+            // Generate the field diff, if told to do so
+            if opts.field_diff {
+                stream.extend(generate_field_diff(&vis, &ident, fields, versions, opts)?);
+            }
+            // Generate the field names, if told to do so
+            if opts.field_names_const {
+                stream.extend(generate_field_names(&vis, &ident, &kept)?);
+            }
+            // Assert its size, if told to do so
+            if opts.size_asserts {
+                stream.extend(generate_size_assert(attrs, &ident, &generics, versions, version)?);
+            }
+            // Generate the tests, if told to do so
+            if opts.gen_tests {
+                if generics.params.is_empty() {
+                    stream.extend(generate_tests(&ident, fields, &kept, versions, version, opts)?);
+                } else {
+                    // We can't, so say so (but only once, in the first version the struct exists in)
+                    let filter: Option<VersionFilter> = get_version_attr(attrs, opts)?;
+                    if versions
+                        .0
+                        .iter()
+                        .find(|v| filter.as_ref().map(|f| f.matches(versions, v)).unwrap_or(true))
+                        .map(|v| v.0 == version.0)
+                        .unwrap_or(false)
+                    {
+                        opts.warn(
+                            generics.span(),
+                            format!("Not generating tests for generic struct '{ident}', as we wouldn't know what to instantiate it with"),
+                        )?;
+                    }
+                }
+            }
 
             // OK, return
             Ok(Some(stream))
         },
         Item::Union(ItemUnion { attrs, vis, union_token, ident, generics, fields }) => {
-            // First, serialize the attributes
-            let mut stream: TokenStream2 = generate_attrs(attrs);
+            // First, serialize the attributes (and those due to renaming it, if any)
+            let (ident, alias): (Cow<Ident>, TokenStream2) = resolve_ident(attrs, ident, versions, version, toplevel, opts)?;
+            let mut stream: TokenStream2 = generate_attrs(attrs, versions, version)?;
+            stream.extend(alias);
             // Serialize the visibility
-            if force_public {
-                let vis: Visibility = Visibility::Public(Pub { span: vis.span() });
-                stream.extend(quote! { #vis });
-            } else {
-                stream.extend(quote! { #vis });
-            }
+            let vis: Cow<Visibility> = wrapper_vis(vis, force_public);
+            stream.extend(quote! { #vis });
             // Serialize some other parts
+            let generics: Cow<Generics> = resolve_generics(attrs, generics, versions, version)?;
+            let where_clause: &Option<WhereClause> = &generics.where_clause;
             stream.extend(quote! {
-                #union_token #ident #generics
+                #union_token #ident #generics #where_clause
             });
             // Serialize the named fields as a whole
             let FieldsNamed { named, brace_token } = fields;
@@ -760,7 +4032,7 @@ fn generate_filtered_item(
             for pair in named.pairs() {
                 // Only add filtered ones too
                 let (field, comma): (&Field, Option<&Comma>) = pair.into_tuple();
-                if let Some(stream) = generate_filtered_field(field, versions, version)? {
+                if let Some(stream) = generate_filtered_field(field, versions, version, opts)? {
                     children.extend(stream);
                     children.extend(quote! { #comma });
                 }
@@ -785,28 +4057,44 @@ fn generate_filtered_item(
             brace_token,
             items,
         }) => {
-            // First, serialize the attributes
-            let mut stream: TokenStream2 = generate_attrs(attrs);
+            // First, serialize the attributes (and those due to renaming it, if any)
+            let (ident, alias): (Cow<Ident>, TokenStream2) = resolve_ident(attrs, ident, versions, version, toplevel, opts)?;
+            let mut stream: TokenStream2 = generate_attrs(attrs, versions, version)?;
+            stream.extend(alias);
             // Serialize the visibility
-            if force_public {
-                let vis: Visibility = Visibility::Public(Pub { span: vis.span() });
-                stream.extend(quote! { #vis });
-            } else {
-                stream.extend(quote! { #vis });
-            }
+            let vis: Cow<Visibility> = wrapper_vis(vis, force_public);
+            stream.extend(quote! { #vis });
             // Serialize some other parts
             stream.extend(quote! { #unsafety #auto_token });
-            // Serialize the restriction(?)
+            // `syn` doesn't parse restrictions (e.g., `impl(crate) trait`) yet, but refuse to silently drop one if it ever does
             if restriction.is_some() {
-                unimplemented!();
+                return Err(Diagnostic::spanned(
+                    ident.span(),
+                    Level::Error,
+                    format!("Trait '{ident}' has an `impl(...)`-restriction, which is not supported by the `#[versioning(...)]`-macro"),
+                ));
+            }
+            // Resolve the supertraits, which may be extended per version
+            let mut bounds: Vec<Cow<TypeParamBound>> = supertraits.iter().map(Cow::Borrowed).collect();
+            for attr in attrs.iter().filter(|attr| attr.path().is_ident("version_supertrait")) {
+                if let Some(bound) = get_version_override::<TypeParamBound>(std::slice::from_ref(attr), "version_supertrait", versions, version)? {
+                    bounds.push(Cow::Owned(bound));
+                }
+            }
+            // Finally serialize 'trait ...', omitting the colon if there are no supertraits (left)
+            let generics: Cow<Generics> = resolve_generics(attrs, generics, versions, version)?;
+            stream.extend(quote! { #trait_token #ident #generics });
+            if !bounds.is_empty() {
+                let colon_token: Token![:] = colon_token.unwrap_or_else(|| Token![:](bounds[0].span()));
+                stream.extend(quote! { #colon_token #(#bounds)+* });
             }
-            // Finally serialize 'trait ...'
-            stream.extend(quote! { #trait_token #ident #generics #colon_token #supertraits });
+            let where_clause: &Option<WhereClause> = &generics.where_clause;
+            stream.extend(quote! { #where_clause });
             // Serialize the items in the trait
             let mut children: TokenStream2 = TokenStream2::new();
             for item in items {
                 // Only serialize those that match the filter test
-                if let Some(stream) = generate_filtered_trait_item(item, versions, version)? {
+                if let Some(stream) = generate_filtered_trait_item(item, versions, version, opts)? {
                     children.extend(stream);
                 }
             }
@@ -827,7 +4115,7 @@ fn generate_filtered_item(
             }
 
             // First, serialize the attributes
-            let mut stream: TokenStream2 = generate_attrs(attrs);
+            let mut stream: TokenStream2 = generate_attrs(attrs, versions, version)?;
             stream.extend(quote! {
                 #unsafety #abi
             });
@@ -835,7 +4123,7 @@ fn generate_filtered_item(
             let mut children: TokenStream2 = TokenStream2::new();
             for item in items {
                 // Only serialize those that match the filter test
-                if let Some(stream) = generate_filtered_foreign_item(item, versions, version)? {
+                if let Some(stream) = generate_filtered_foreign_item(item, versions, version, opts)? {
                     children.extend(stream);
                 }
             }
@@ -845,23 +4133,26 @@ fn generate_filtered_item(
             Ok(Some(stream))
         },
         Item::Impl(ItemImpl { attrs, defaultness, unsafety, impl_token, generics, trait_, self_ty, brace_token, items }) => {
-            // Since it doesn't have visibility, error if we would be forced to update it
-            if force_public {
-                return Err(Diagnostic::spanned(
-                    item.span(),
-                    Level::Error,
-                    "Cannot use `#[versioning(...)]` on an impl block; instead, wrap it in a module to decide internal visibility yourself".into(),
-                ));
-            }
+            // Note that `force_public` doesn't apply, as impls don't have visibility (and are visible wherever their types are)
 
             // Serialize as far as we can before it gets gnarly
-            let mut stream: TokenStream2 = generate_attrs(attrs);
+            let mut stream: TokenStream2 = generate_attrs(attrs, versions, version)?;
+            let generics: Cow<Generics> = resolve_generics(attrs, generics, versions, version)?;
             stream.extend(quote! {
                 #defaultness #unsafety #impl_token #generics
             });
-            // Serialize the 'for trait' part, if any
-            if let Some((not, name, for_token)) = trait_ {
-                stream.extend(quote! { #not #name #for_token });
+            // Serialize the 'for trait' part, if any (and possibly overridden per version)
+            match get_version_override::<ImplTrait>(attrs, "version_impl_trait", versions, version)? {
+                Some(ImplTrait::Trait(not, name)) => {
+                    let for_token: Token![for] = trait_.as_ref().map(|(_, _, for_token)| *for_token).unwrap_or_else(|| Token![for](name.span()));
+                    stream.extend(quote! { #not #name #for_token });
+                },
+                Some(ImplTrait::Inherent) => {},
+                None => {
+                    if let Some((not, name, for_token)) = trait_ {
+                        stream.extend(quote! { #not #name #for_token });
+                    }
+                },
             }
             // Serialize the type
             let where_clause: &Option<WhereClause> = &generics.where_clause;
@@ -870,7 +4161,7 @@ fn generate_filtered_item(
             let mut children: TokenStream2 = TokenStream2::new();
             for item in items {
                 // Keep only non-filtered items
-                if let Some(stream) = generate_filtered_impl_item(item, versions, version)? {
+                if let Some(stream) = generate_filtered_impl_item(item, versions, version, opts)? {
                     children.extend(stream);
                 }
             }
@@ -882,66 +4173,123 @@ fn generate_filtered_item(
 
         // For these, just mod the visibility if told to do so
         Item::Const(ItemConst { attrs, vis, const_token, ident, generics, colon_token, ty, eq_token, expr, semi_token }) => {
-            let vis: Cow<Visibility> = if force_public { Cow::Owned(Visibility::Public(Pub { span: vis.span() })) } else { Cow::Borrowed(vis) };
-            let mut stream = generate_attrs(attrs);
+            let vis: Cow<Visibility> = wrapper_vis(vis, force_public);
+            let expr: Cow<Expr> = match get_version_override(attrs, "version_value", versions, version)? {
+                Some(expr) => Cow::Owned(expr),
+                None => Cow::Borrowed(expr),
+            };
+            let (ident, alias): (Cow<Ident>, TokenStream2) = resolve_ident(attrs, ident, versions, version, toplevel, opts)?;
+            let mut stream = generate_attrs(attrs, versions, version)?;
+            stream.extend(alias);
             stream.extend(quote! {
                 #vis #const_token #ident #generics #colon_token #ty #eq_token #expr #semi_token
             });
             Ok(Some(stream))
         },
         Item::ExternCrate(ItemExternCrate { attrs, vis, extern_token, crate_token, ident, rename, semi_token }) => {
-            let vis: Cow<Visibility> = if force_public { Cow::Owned(Visibility::Public(Pub { span: vis.span() })) } else { Cow::Borrowed(vis) };
-            let mut stream: TokenStream2 = generate_attrs(attrs);
+            let vis: Cow<Visibility> = wrapper_vis(vis, force_public);
+            let mut stream: TokenStream2 = generate_attrs(attrs, versions, version)?;
             stream.extend(quote! {
                 #vis #extern_token #crate_token #ident
             });
-            if let Some((as_token, name)) = rename {
-                stream.extend(quote! { #as_token, #name });
+            // Serialize the rename, which may be overridden per version
+            match get_version_override::<Ident>(attrs, "version_extern_rename", versions, version)? {
+                Some(name) => {
+                    let as_token: Token![as] = rename.as_ref().map(|(as_token, _)| *as_token).unwrap_or_else(|| Token![as](name.span()));
+                    stream.extend(quote! { #as_token #name });
+                },
+                None => {
+                    if let Some((as_token, name)) = rename {
+                        stream.extend(quote! { #as_token #name });
+                    }
+                },
             }
             stream.extend(quote! { #semi_token });
             Ok(Some(stream))
         },
         Item::Fn(ItemFn { attrs, vis, sig, block }) => {
-            // For now, function bodies are not yet nested
-            let vis: Cow<Visibility> = if force_public { Cow::Owned(Visibility::Public(Pub { span: vis.span() })) } else { Cow::Borrowed(vis) };
-            let mut stream = generate_attrs(attrs);
+            let vis: Cow<Visibility> = wrapper_vis(vis, force_public);
+            let mut sig: Cow<Signature> = resolve_sig(attrs, sig, versions, version)?;
+            let (ident, alias): (Cow<Ident>, TokenStream2) = resolve_ident(attrs, &sig.ident, versions, version, toplevel, opts)?;
+            if let Cow::Owned(ident) = ident {
+                sig.to_mut().ident = ident;
+            }
+            let block: Block = generate_filtered_block(block, versions, version)?;
+            let mut stream = generate_attrs(attrs, versions, version)?;
+            stream.extend(alias);
             stream.extend(quote! {
                 #vis #sig #block
             });
             Ok(Some(stream))
         },
         Item::Macro(ItemMacro { attrs, ident, mac, semi_token }) => {
-            let mut stream: TokenStream2 = generate_attrs(attrs);
-            stream.extend(quote! { #ident #mac #semi_token });
+            let mut stream: TokenStream2 = generate_attrs(attrs, versions, version)?;
+            // The identifier (of `macro_rules!`) goes in between the path and the body, so let `syn` serialize it
+            let item: ItemMacro = ItemMacro { attrs: vec![], ident: ident.clone(), mac: mac.clone(), semi_token: *semi_token };
+            stream.extend(quote! { #item });
             Ok(Some(stream))
         },
         Item::Static(ItemStatic { attrs, vis, static_token, mutability, ident, colon_token, ty, eq_token, expr, semi_token }) => {
-            let vis: Cow<Visibility> = if force_public { Cow::Owned(Visibility::Public(Pub { span: vis.span() })) } else { Cow::Borrowed(vis) };
-            let mut stream = generate_attrs(attrs);
+            let vis: Cow<Visibility> = wrapper_vis(vis, force_public);
+            let expr: Cow<Expr> = match get_version_override(attrs, "version_value", versions, version)? {
+                Some(expr) => Cow::Owned(expr),
+                None => Cow::Borrowed(expr),
+            };
+            let (ident, alias): (Cow<Ident>, TokenStream2) = resolve_ident(attrs, ident, versions, version, toplevel, opts)?;
+            let mut stream = generate_attrs(attrs, versions, version)?;
+            stream.extend(alias);
             stream.extend(quote! {
                 #vis #static_token #mutability #ident #colon_token #ty #eq_token #expr #semi_token
             });
             Ok(Some(stream))
         },
         Item::TraitAlias(ItemTraitAlias { attrs, vis, trait_token, ident, generics, eq_token, bounds, semi_token }) => {
-            let vis: Cow<Visibility> = if force_public { Cow::Owned(Visibility::Public(Pub { span: vis.span() })) } else { Cow::Borrowed(vis) };
-            let mut stream = generate_attrs(attrs);
+            let vis: Cow<Visibility> = wrapper_vis(vis, force_public);
+            let (ident, alias): (Cow<Ident>, TokenStream2) = resolve_ident(attrs, ident, versions, version, toplevel, opts)?;
+            // Note that the where-clause comes _after_ the bounds (which may be empty)
+            let where_clause: &Option<WhereClause> = &generics.where_clause;
+            let mut stream = generate_attrs(attrs, versions, version)?;
+            stream.extend(alias);
             stream.extend(quote! {
-                #vis #trait_token #ident #generics #eq_token #bounds #semi_token
+                #vis #trait_token #ident #generics #eq_token #bounds #where_clause #semi_token
             });
             Ok(Some(stream))
         },
         Item::Type(ItemType { attrs, vis, type_token, ident, generics, eq_token, ty, semi_token }) => {
-            let vis: Cow<Visibility> = if force_public { Cow::Owned(Visibility::Public(Pub { span: vis.span() })) } else { Cow::Borrowed(vis) };
-            let mut stream = generate_attrs(attrs);
+            let vis: Cow<Visibility> = wrapper_vis(vis, force_public);
+            let ty: Cow<Type> = resolve_type(attrs, ty, versions, version)?;
+            let (ident, alias): (Cow<Ident>, TokenStream2) = resolve_ident(attrs, ident, versions, version, toplevel, opts)?;
+            let where_clause: &Option<WhereClause> = &generics.where_clause;
+            let mut stream = generate_attrs(attrs, versions, version)?;
+            stream.extend(alias);
             stream.extend(quote! {
-                #vis #type_token #ident #generics #eq_token #ty #semi_token
+                #vis #type_token #ident #generics #where_clause #eq_token #ty #semi_token
             });
             Ok(Some(stream))
         },
         Item::Use(ItemUse { attrs, vis, use_token, leading_colon, tree, semi_token }) => {
-            let vis: Cow<Visibility> = if force_public { Cow::Owned(Visibility::Public(Pub { span: vis.span() })) } else { Cow::Borrowed(vis) };
-            let mut stream = generate_attrs(attrs);
+            let vis: Cow<Visibility> = match force_public.then(|| wrapped_use_vis(vis)).flatten() {
+                Some(vis) => Cow::Owned(vis),
+                None => Cow::Borrowed(vis),
+            };
+            // Find the names that don't exist in this version (which must be declared)
+            let gated: HashMap<Ident, bool> = get_version_use_items(attrs, tree, versions, version)?;
+            // Overridden trees (e.g., a glob instead of a curated list) replace the leading colon too
+            let (leading_colon, tree): (&Option<Token![::]>, Cow<UseTree>) = match get_version_override(attrs, "version_use", versions, version)? {
+                Some(tree) => (&None, Cow::Owned(tree)),
+                None => (leading_colon, Cow::Borrowed(tree)),
+            };
+            // Then drop them
+            let tree: Cow<UseTree> = if gated.is_empty() {
+                tree
+            } else {
+                match filter_use_tree(&tree, &gated) {
+                    Some(tree) => Cow::Owned(tree),
+                    // There's nothing left to import
+                    None => return Ok(None),
+                }
+            };
+            let mut stream = generate_attrs(attrs, versions, version)?;
             stream.extend(quote! {
                 #vis #use_token #leading_colon #tree #semi_token
             });
@@ -956,6 +4304,63 @@ fn generate_filtered_item(
     }
 }
 
+/// Checks that the modules in the given item are not nested deeper than [`MAX_MODULE_DEPTH`].
+///
+/// This walks the modules with an explicit stack, so it is safe to do before generating them recursively.
+///
+/// # Arguments
+/// - `item`: The [`Item`] given to the `#[versioning(...)]`-macro.
+///
+/// # Errors
+/// This function errors if any module is nested too deeply, pointing at the first one that is.
+fn check_module_depth(item: &Item) -> Result<(), Diagnostic> {
+    let mut todo: Vec<(&Item, usize)> = vec![(item, 1)];
+    while let Some((item, depth)) = todo.pop() {
+        if let Item::Mod(ItemMod { ident, content: Some((_, items)), .. }) = item {
+            if depth > MAX_MODULE_DEPTH {
+                return Err(Diagnostic::spanned(
+                    ident.span(),
+                    Level::Error,
+                    format!("Module '{ident}' is nested more than {MAX_MODULE_DEPTH} modules deep, which is not supported"),
+                ));
+            }
+            todo.extend(items.iter().map(|item| (item, depth + 1)));
+        }
+    }
+    Ok(())
+}
+
+/// Computes a key to compare the code generated for different versions by.
+///
+/// This is simply the serialized code, except that the toplevel module name is ignored (as it's renamed to the version when
+/// modules aren't nested). Attributes are deliberately part of the key, as they may change the layout or the impls of what
+/// they're on, and as such versions differing in them can't share their code.
+///
+/// # Arguments
+/// - `stream`: The [`TokenStream2`] generated for the `version`.
+/// - `module`: The identifier of the module generated for the version for which the `stream` was generated.
+///
+/// # Returns
+/// A [`String`] that is equal for two versions if they generated the same code.
+fn coalesce_key(stream: &TokenStream2, module: &Ident) -> String {
+    let mut key: TokenStream2 = TokenStream2::new();
+    let mut after_mod: bool = false;
+    for token in stream.clone() {
+        if let TokenTree::Ident(ident) = &token {
+            if after_mod && ident == module {
+                key.extend(quote! { __version__ });
+                after_mod = false;
+                continue;
+            }
+            after_mod = ident == "mod";
+        } else {
+            after_mod = false;
+        }
+        key.extend([token]);
+    }
+    key.to_string()
+}
+
 
 
 
@@ -963,6 +4368,19 @@ fn generate_filtered_item(
 /***** LIBRARY *****/
 /// Handles the toplevel `#[versioned(...)]` call.
 ///
+/// The generated version modules are guaranteed to be emitted in the order in which the versions are declared, unless the
+/// `output_order` option says otherwise.
+///
+/// If the versions are gated (i.e., `features` or `cfg_mode` is given), any `#[cfg(...)]` on the item itself is merged
+/// with the version gate into a single `#[cfg(all(<gate>, <cfg>...))]`. Those on nested items and fields are left as-is.
+///
+/// The same goes for `#[version_feature("...")]`, which gates an item on a feature _in addition_ to the version gate (it
+/// never replaces it, as that would make the item appear in versions that are disabled). On nested items, or if the
+/// versions aren't gated, it is emitted as a plain `#[cfg(feature = "...")]`.
+///
+/// Any item, field or variant of which the `#[version(...)]` matches none of the versions triggers a warning, as that is
+/// usually a typo. Give `deny_warnings` to make it an error instead.
+///
 /// # Arguments
 /// - `attrs`: The given attributes to parse.
 /// - `input`: The input [`TokenStream2`] to parse.
@@ -974,57 +4392,263 @@ fn generate_filtered_item(
 /// This function may error if it failed to correctly understand the input.
 pub fn call(attrs: TokenStream2, input: TokenStream2) -> Result<TokenStream2, Diagnostic> {
     // Parse the attributes first as a list of versions
-    let (versions, opts): (VersionList, Options) = parse_input(attrs)?;
+    let (versions, mut opts): (VersionList, Options) = parse_input(attrs)?;
 
     // Next, parse the input as a module
     let input_span: Span = input.span();
-    let item: Item = match syn::parse2(input) {
+    let mut item: Item = match syn::parse2(input) {
         Ok(item) => item,
         Err(err) => {
-            return Err(Diagnostic::spanned(input_span, Level::Error, err.to_string()));
+            // Point at the failing tokens if we know where they are, or else at the input as a whole
+            let span: Span = if err.span().source_text().is_some() { err.span() } else { input_span };
+            return Err(Diagnostic::spanned(span, Level::Error, err.to_string()));
         },
     };
+    check_module_depth(&item)?;
+    if opts.type_suffix && !matches!(item, Item::Enum(_) | Item::Struct(_) | Item::Trait(_) | Item::TraitAlias(_) | Item::Type(_) | Item::Union(_)) {
+        // We'd have to rename every use of the item (or of the items in a module) too, which is fine for types but not for values
+        let what: &str = match &item {
+            Item::Const(_) => "constant",
+            Item::ExternCrate(_) => "extern crate",
+            Item::Fn(_) => "function",
+            Item::Mod(_) => "module",
+            Item::Static(_) => "static",
+            _ => "item",
+        };
+        let (span, name): (Span, String) = match item_ident(&item) {
+            Some(ident) => (ident.span(), format!(" '{ident}'")),
+            None => (item.span(), String::new()),
+        };
+        return Err(Diagnostic::spanned(
+            span,
+            Level::Error,
+            format!("`type_suffix` cannot be used on {what}{name}, only on structs, enums, unions, traits, trait aliases and type aliases"),
+        ));
+    }
+
+    // Parse the filters once (resolving what any `else`s mean) before anything reads them
+    let mut collector: FilterCollector = FilterCollector { filters: HashMap::new(), error: None };
+    collector.visit_item_mut(&mut item);
+    if let Some(err) = collector.error {
+        return Err(err);
+    }
+    opts.filters = collector.filters;
+
+    // If we're gating, then the item's own `#[cfg(...)]`s and `#[version_feature(...)]`s are merged with the version gate instead of emitted separately
+    let mut cfgs: Vec<TokenStream2> = vec![];
+    if opts.is_gated() {
+        if let Some(attrs) = item_attrs_mut(&mut item) {
+            let mut res: Result<(), Diagnostic> = Ok(());
+            attrs.retain(|attr| {
+                if attr.path().is_ident("cfg") {
+                    match attr.meta.require_list() {
+                        Ok(list) => cfgs.push(list.tokens.clone()),
+                        Err(err) => res = Err(Diagnostic::spanned(attr.span(), Level::Error, err.to_string())),
+                    }
+                    false
+                } else if attr.path().is_ident("version_feature") {
+                    match attr.parse_args::<LitStr>() {
+                        Ok(feature) => cfgs.push(quote! { feature = #feature }),
+                        Err(err) => {
+                            res = Err(Diagnostic::spanned(err.span(), Level::Error, format!("Failed to parse `#[version_feature(...)]`: {err}")))
+                        },
+                    }
+                    false
+                } else {
+                    true
+                }
+            });
+            res?;
+        }
+    }
+
+    // Resolve the names of the version modules, which may be overridden by the item
+    if let Some(attrs) = item_attrs(&item) {
+        for version in &versions.0 {
+            if let Some(name) = get_version_override::<Ident>(attrs, "version_module_name", &versions, version)? {
+                opts.module_names.insert(version.0.to_string(), name);
+            }
+        }
+    }
+    let mut names: HashMap<String, &Version> = HashMap::with_capacity(versions.0.len());
+    for version in &versions.0 {
+        let name: Ident = opts.module_ident(version);
+        if let Some(other) = names.insert(name.to_string(), version) {
+            return Err(Diagnostic::spanned(
+                name.span(),
+                Level::Error,
+                format!("Versions '{}' and '{}' would both generate a module named '{name}'", other.0, version.0),
+            ));
+        }
+    }
 
     // Generate new impls from the parsed one for every version in the `versions`
-    let mut impls: Vec<TokenStream2> = Vec::with_capacity(versions.0.len());
+    FILTERED_OUT.with(|filtered| filtered.borrow_mut().clear());
+    DEAD.with(|dead| dead.borrow_mut().clear());
+    let wrap_in_mod: bool = !matches!(item, Item::Mod(_)) || opts.nest_toplevel_modules;
+    let mut groups: Vec<(Vec<&Version>, TokenStream2)> = Vec::with_capacity(versions.0.len());
+    let mut prev_key: Option<String> = None;
     for version in &versions.0 {
         // Collect the filtered version of the implementation
-        let wrap_in_mod: bool = !matches!(item, Item::Mod(_)) || opts.nest_toplevel_modules;
-        let old_vis: Option<&Visibility> = item_vis(&item);
-        let mut stream: TokenStream2 = match generate_filtered_item(&item, &versions, version, true, wrap_in_mod)? {
+        let mut stream: TokenStream2 = match generate_filtered_item(&item, &versions, version, true, wrap_in_mod && opts.force_public, &opts)? {
             Some(item) => item,
-            // Filtered out
-            None => continue,
+            // Filtered out (which also breaks any chain of identical versions)
+            None => {
+                prev_key = None;
+                continue;
+            },
         };
+        if opts.schema_fn {
+            stream.extend(generate_schema_fn(&item, &versions, version, &opts)?);
+        }
+
+        // Merge it with the previous version if they are identical and we're told to do so
+        if opts.coalesce {
+            let key: String = coalesce_key(&stream, &opts.module_ident(version));
+            if prev_key.as_ref() == Some(&key) {
+                if let Some((group, _)) = groups.last_mut() {
+                    group.push(version);
+                    continue;
+                }
+            }
+            prev_key = Some(key);
+        }
+        groups.push((vec![version], stream));
+    }
+    FILTERED_OUT.with(|filtered| filtered.borrow_mut().clear());
+
+    // Anything that's in none of the versions is probably a mistake
+    for (_, span, what) in DEAD.with(|dead| std::mem::take(&mut *dead.borrow_mut())) {
+        opts.warn(span, format!("{what} is filtered out of every version (does its `#[version(...)]` refer to the right versions?)"))?;
+    }
 
+    // Wrap the generated code of every (group of) version(s)
+    let old_vis: Option<&Visibility> = item_vis(&item);
+    let mut impls: Vec<(&Version, TokenStream2)> = Vec::with_capacity(groups.len());
+    let mut gates: Vec<(Vec<&Version>, Option<TokenStream2>)> = Vec::with_capacity(groups.len());
+    for (group, mut stream) in groups {
         // If we are wrapping, then do so
+        let ident: Ident = opts.module_ident(group[0]);
         if wrap_in_mod {
             // Resolve the input
-            let ident: &Ident = &version.0;
             let vis: Cow<Visibility> = if let Some(old_vis) = old_vis { Cow::Borrowed(old_vis) } else { Cow::Owned(Visibility::Inherited) };
             // Wrap
-            stream = quote! {
+            stream = quote_spanned! { ident.span() =>
                 #vis mod #ident {
                     #stream
                 }
             };
         }
 
+        // Apply the attributes that are shared by all versions
+        let pass_attrs: &[Meta] = &opts.pass_attrs;
+        stream = quote_spanned! { ident.span() =>
+            #(#[#pass_attrs])*
+            #stream
+        };
+
         // Check if we need to inject the feature gate or not
-        if opts.features {
-            let feature: String = version.0.to_string();
-            stream = quote! {
-                #[cfg(feature = #feature)]
+        if opts.is_gated() {
+            let mut preds: Vec<TokenStream2> = Vec::with_capacity(group.len());
+            for version in &group {
+                if let Some(pred) = opts.version_cfg(version)? {
+                    preds.push(pred);
+                }
+            }
+            let pred: TokenStream2 = if preds.len() == 1 {
+                preds[0].clone()
+            } else {
+                quote! { any(#(#preds),*) }
+            };
+            let gate: TokenStream2 = if cfgs.is_empty() {
+                pred
+            } else {
+                quote! { all(#pred, #(#cfgs),*) }
+            };
+            stream = quote_spanned! { ident.span() =>
+                #[cfg(#gate)]
                 #stream
             };
+            gates.push((group.clone(), Some(gate)));
+
+            // Any merged versions refer to the first one in the group
+            let vis: Cow<Visibility> = if let Some(old_vis) = old_vis { Cow::Borrowed(old_vis) } else { Cow::Owned(Visibility::Inherited) };
+            for (alias, pred) in group.iter().zip(preds.iter()).skip(1) {
+                let alias: Ident = opts.module_ident(alias);
+                let pred: TokenStream2 = if cfgs.is_empty() {
+                    pred.clone()
+                } else {
+                    quote! { all(#pred, #(#cfgs),*) }
+                };
+                stream.extend(quote_spanned! { alias.span() =>
+                    #[cfg(#pred)]
+                    #[allow(unused_imports)]
+                    #vis use self::#ident as #alias;
+                });
+            }
+        } else {
+            gates.push((group.clone(), None));
+
+            // Any merged versions refer to the first one in the group
+            let vis: Cow<Visibility> = if let Some(old_vis) = old_vis { Cow::Borrowed(old_vis) } else { Cow::Owned(Visibility::Inherited) };
+            for alias in group.iter().skip(1) {
+                let alias: Ident = opts.module_ident(alias);
+                stream.extend(quote_spanned! { alias.span() =>
+                    #[allow(unused_imports)]
+                    #vis use self::#ident as #alias;
+                });
+            }
         }
 
         // Epic, store it!
-        impls.push(stream);
+        impls.push((group[0], stream));
     }
 
+    // Emit them in the desired order
+    let impls: Vec<TokenStream2> = match opts.output_order {
+        // They are already in this order
+        OutputOrder::Declaration => impls.into_iter().map(|(_, stream)| stream).collect(),
+        OutputOrder::Reverse => impls.into_iter().rev().map(|(_, stream)| stream).collect(),
+        OutputOrder::Semver => {
+            let mut keyed: Vec<(Vec<u64>, TokenStream2)> = Vec::with_capacity(impls.len());
+            for (version, stream) in impls {
+                keyed.push((semver_key(version)?, stream));
+            }
+            keyed.sort_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs));
+            keyed.into_iter().map(|(_, stream)| stream).collect()
+        },
+    };
+
+    // Generate the conversions between them, if told to do so
+    let conversions: TokenStream2 = generate_conversions(&item, &versions, &gates, &opts)?;
+
     // Done
     Ok(quote! {
         #(#impls)*
+        #conversions
     })
 }
+
+/// Runs [`call()`] on source text, for testing what it generates.
+///
+/// Note that any warnings must be avoided (or turned into errors with `deny_warnings`), as they can only be emitted from
+/// within the macro itself.
+///
+/// # Arguments
+/// - `attrs`: The source of the attributes, i.e., the stuff in between the brackets in `#[versioning(...)]`.
+/// - `input`: The source of the item that is being attributed.
+///
+/// # Returns
+/// The generated code, serialized with [`TokenStream2`]'s [`Display`](std::fmt::Display)-implementation.
+///
+/// # Errors
+/// This function errors with the message of the error if either source failed to tokenize, or if [`call()`] failed.
+#[cfg(test)]
+pub fn expand_to_string(attrs: &str, input: &str) -> Result<String, String> {
+    let attrs: TokenStream2 = attrs.parse().map_err(|err| format!("Failed to tokenize attributes: {err}"))?;
+    let input: TokenStream2 = input.parse().map_err(|err| format!("Failed to tokenize input: {err}"))?;
+    match call(attrs, input) {
+        Ok(res) => Ok(res.to_string()),
+        Err(err) => Err(err.message().into()),
+    }
+}
@@ -0,0 +1,25 @@
+//  UI.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:09:13
+//  Last edited:
+//    14 Oct 2026, 10:09:52
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Checks that the errors of (or in code generated by) the
+//!   `#[versioning(...)]`-macro point at the right source.
+//!
+//!   Run with `TRYBUILD=overwrite cargo test --test ui` to update the
+//!   expected output in `tests/ui/`.
+//
+
+
+/***** TESTS *****/
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
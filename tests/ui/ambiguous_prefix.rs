@@ -0,0 +1,24 @@
+//  AMBIGUOUS PREFIX.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:35:02
+//  Last edited:
+//    14 Oct 2026, 10:35:02
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Checks that ordered filters reject prefixes of multiple versions.
+//
+
+use versioning::versioning;
+
+#[versioning(v1_0_0, v1_1_0, v2_0_0)]
+pub struct Config {
+    pub name: String,
+    #[version(min("v1"))]
+    pub port: u16,
+}
+
+fn main() {}
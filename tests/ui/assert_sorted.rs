@@ -0,0 +1,22 @@
+//  ASSERT SORTED.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:45:59
+//  Last edited:
+//    14 Oct 2026, 10:45:59
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Checks that `assert_sorted` rejects versions that are out of order.
+//
+
+use versioning::versioning;
+
+#[versioning(v1_0_0, v2_0_0, v1_1_0, assert_sorted = "semver")]
+pub struct Config {
+    pub name: String,
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+//  BAD RANGES.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:45:53
+//  Last edited:
+//    14 Oct 2026, 10:45:53
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Checks that invalid version ranges are rejected.
+//
+
+use versioning::versioning;
+
+#[versioning(v1_0_2..v1_0_0)]
+pub struct Config {
+    pub name: String,
+}
+
+#[versioning(v1_0_0..=v2_0_0)]
+pub struct Server {
+    pub port: u16,
+}
+
+#[versioning(v1_0_0..=v1_0_x)]
+pub struct Client {
+    pub host: String,
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+//  BOUND INVALID.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:46:02
+//  Last edited:
+//    14 Oct 2026, 10:46:02
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Checks that `#[version_bound(...)]` reports predicates that fail to
+//!   parse.
+//
+
+use versioning::versioning;
+
+#[versioning(v1_0_0, v2_0_0)]
+#[version_bound("v2_0_0", "T: +")]
+pub struct Wrapper<T> {
+    pub value: T,
+}
+
+fn main() {}
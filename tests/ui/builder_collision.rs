@@ -0,0 +1,25 @@
+//  BUILDER COLLISION.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:10:48
+//  Last edited:
+//    14 Oct 2026, 10:11:01
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Checks that fields colliding with the methods of the generated
+//!   builder are reported.
+//
+
+use versioning::versioning;
+
+#[versioning(v1_0_0, v2_0_0, builder = true)]
+pub struct Job {
+    pub name: String,
+    #[version("v2_0_0")]
+    pub build: u32,
+}
+
+fn main() {}
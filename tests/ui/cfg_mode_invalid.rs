@@ -0,0 +1,22 @@
+//  CFG MODE INVALID.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:45:52
+//  Last edited:
+//    14 Oct 2026, 10:45:52
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Checks that `cfg_mode` templates must produce a `cfg(...)`.
+//
+
+use versioning::versioning;
+
+#[versioning(v1_0_0, v2_0_0, cfg_mode = "feature = \"{version}\"")]
+pub struct Config {
+    pub name: String,
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+//  DEAD ITEM.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:34:57
+//  Last edited:
+//    14 Oct 2026, 10:34:57
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Checks that fields that exist in no version are reported (as an
+//!   error, because of `deny_warnings`).
+//
+
+use versioning::versioning;
+
+#[versioning(v1_0_0, v2_0_0, deny_warnings = true)]
+pub struct Config {
+    pub name: String,
+    #[version(all("v1_0_0", "v2_0_0"))]
+    pub port: u16,
+}
+
+fn main() {}
@@ -0,0 +1,48 @@
+//  DEPTH LIMIT.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:35:04
+//  Last edited:
+//    14 Oct 2026, 10:35:04
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Checks that modules nested deeper than the compiler's default
+//!   `recursion_limit` are rejected.
+//
+
+use versioning::versioning;
+
+#[versioning(v1_0_0, v2_0_0)]
+mod defs {
+    mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m {
+    mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m {
+    mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m {
+    mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m {
+    mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m {
+    mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m {
+    mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m {
+    mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m {
+    mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m {
+    mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m {
+    mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m {
+    mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m {
+    mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m { mod m {
+    } } } } } } } } } }
+    } } } } } } } } } }
+    } } } } } } } } } }
+    } } } } } } } } } }
+    } } } } } } } } } }
+    } } } } } } } } } }
+    } } } } } } } } } }
+    } } } } } } } } } }
+    } } } } } } } } } }
+    } } } } } } } } } }
+    } } } } } } } } } }
+    } } } } } } } } } }
+    } } } } } } } } } }
+}
+
+fn main() {}
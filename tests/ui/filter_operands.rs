@@ -0,0 +1,30 @@
+//  FILTER OPERANDS.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:35:00
+//  Last edited:
+//    14 Oct 2026, 10:35:00
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Checks the errors for operands of `#[version(...)]` that are neither
+//!   versions nor operators.
+//
+
+use versioning::versioning;
+
+#[versioning(v1_0_0, v2_0_0)]
+pub struct Config {
+    #[version(42)]
+    pub name: String,
+}
+
+#[versioning(v1_0_0, v2_0_0)]
+pub struct Server {
+    #[version(foo::min("v1_0_0"))]
+    pub port: u16,
+}
+
+fn main() {}
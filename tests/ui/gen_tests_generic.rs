@@ -0,0 +1,23 @@
+//  GEN TESTS GENERIC.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:20:27
+//  Last edited:
+//    14 Oct 2026, 10:20:35
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Checks that generic structs are reported when they can't get any
+//!   generated tests.
+//
+
+use versioning::versioning;
+
+#[versioning(v1_0_0, v2_0_0, gen_tests = true, deny_warnings = true)]
+pub struct Wrapper<T> {
+    pub value: T,
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+//  MODULE NAME COLLISION.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:34:54
+//  Last edited:
+//    14 Oct 2026, 10:34:54
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Checks that versions whose modules would get the same name are
+//!   rejected.
+//
+
+use versioning::versioning;
+
+#[versioning(v1_0_0, v2_0_0)]
+#[version_module_name("v1_0_0", legacy)]
+#[version_module_name("v2_0_0", legacy)]
+pub struct Config {
+    pub name: String,
+}
+
+fn main() {}
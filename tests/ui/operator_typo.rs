@@ -0,0 +1,29 @@
+//  OPERATOR TYPO.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:45:57
+//  Last edited:
+//    14 Oct 2026, 10:45:57
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Checks that unknown operators suggest the closest known one, if any.
+//
+
+use versioning::versioning;
+
+#[versioning(v1_0_0, v2_0_0)]
+pub struct Config {
+    #[version(minimum("v2_0_0"))]
+    pub name: String,
+}
+
+#[versioning(v1_0_0, v2_0_0)]
+pub struct Server {
+    #[version(latest("v2_0_0"))]
+    pub port: u16,
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+//  PARSE ERROR SPAN.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:34:52
+//  Last edited:
+//    14 Oct 2026, 10:34:52
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Checks that a syntax error in the input points at the failing tokens,
+//!   not at the whole item.
+//
+
+use versioning::versioning;
+
+#[versioning(v1_0_0, v2_0_0)]
+mod defs {
+    pub struct Config {
+        pub name: String,
+    }
+
+    pub fn port() -> u16 { let }
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+//  PROVIDED WITHOUT DEFAULT.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:46:05
+//  Last edited:
+//    14 Oct 2026, 10:46:05
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Checks that associated constants can only be provided in versions in
+//!   which they have a value.
+//
+
+use versioning::versioning;
+
+#[versioning(v1_0_0, v2_0_0)]
+pub trait Limits {
+    #[version_provided("v2_0_0")]
+    const MAX: u8;
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+//  RENAME UNNAMED.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:43:16
+//  Last edited:
+//    14 Oct 2026, 10:43:16
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Checks that `#[version_rename(...)]` is rejected on items without a
+//!   name of their own.
+//
+
+use versioning::versioning;
+
+#[versioning(v1_0_0, v2_0_0)]
+mod defs {
+    #[version_rename("v2_0_0", Map)]
+    pub use std::collections::HashMap;
+}
+
+fn main() {}
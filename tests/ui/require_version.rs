@@ -0,0 +1,25 @@
+//  REQUIRE VERSION.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:34:56
+//  Last edited:
+//    14 Oct 2026, 10:34:56
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Checks that `require_version` rejects fields without a
+//!   `#[version(...)]`.
+//
+
+use versioning::versioning;
+
+#[versioning(v1_0_0, v2_0_0, require_version = true)]
+pub struct Config {
+    #[version(default)]
+    pub name: String,
+    pub port: u16,
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+//  SIZE ASSERT GENERIC.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:44:59
+//  Last edited:
+//    14 Oct 2026, 10:44:59
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Checks that `#[version_size(...)]` is rejected on generic items.
+//
+
+use versioning::versioning;
+
+#[versioning(v1_0_0, v2_0_0, size_asserts = true)]
+#[version_size("v1_0_0", 8)]
+pub struct Wrapper<T> {
+    pub value: T,
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+//  SPAN FIELD.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:09:13
+//  Last edited:
+//    14 Oct 2026, 10:09:52
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Checks that an error in a versioned field points at the field.
+//
+
+use versioning::versioning;
+
+#[versioning(v1_0_0, v2_0_0)]
+#[derive(Clone)]
+pub struct Config {
+    pub name: String,
+    #[version("v2_0_0")]
+    pub lock: std::sync::Mutex<()>,
+}
+
+fn main() {}
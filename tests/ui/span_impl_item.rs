@@ -0,0 +1,29 @@
+//  SPAN IMPL ITEM.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:09:13
+//  Last edited:
+//    14 Oct 2026, 10:09:52
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Checks that an error in a versioned impl item points at the item.
+//
+
+use versioning::versioning;
+
+#[versioning(v1_0_0, v2_0_0)]
+pub mod api {
+    pub struct Config;
+
+    impl Config {
+        pub fn name(&self) -> &'static str { "config" }
+
+        #[version("v2_0_0")]
+        pub fn port(&self) -> u16 { "8080" }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+//  SPAN VARIANT.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:09:13
+//  Last edited:
+//    14 Oct 2026, 10:09:52
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Checks that an error in a versioned variant points at the variant.
+//
+
+use versioning::versioning;
+
+#[versioning(v1_0_0, v2_0_0)]
+#[derive(Clone)]
+pub enum Message {
+    Ping,
+    #[version("v2_0_0")]
+    Lock(std::sync::Mutex<()>),
+}
+
+fn main() {}
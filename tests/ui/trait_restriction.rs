@@ -0,0 +1,24 @@
+//  TRAIT RESTRICTION.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:39:42
+//  Last edited:
+//    14 Oct 2026, 10:40:07
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Checks that traits with an `impl(...)`-restriction are rejected with
+//!   an error instead of crashing the macro. Neither the compiler nor
+//!   `syn` parse restrictions yet, so for now the error is a parse error.
+//
+
+use versioning::versioning;
+
+#[versioning(v1_0_0, v2_0_0)]
+pub impl(crate) trait Sealed {
+    fn seal(&self);
+}
+
+fn main() {}
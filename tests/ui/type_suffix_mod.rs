@@ -0,0 +1,24 @@
+//  TYPE SUFFIX MOD.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:23:15
+//  Last edited:
+//    14 Oct 2026, 10:23:23
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Checks that `type_suffix` is rejected on modules.
+//
+
+use versioning::versioning;
+
+#[versioning(v1_0_0, v2_0_0, type_suffix = true)]
+mod defs {
+    pub struct Config {
+        pub name: String,
+    }
+}
+
+fn main() {}
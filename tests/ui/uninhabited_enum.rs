@@ -0,0 +1,26 @@
+//  UNINHABITED ENUM.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:34:53
+//  Last edited:
+//    14 Oct 2026, 10:34:53
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Checks that enums losing all their variants in a version are
+//!   reported (as an error, because of `deny_warnings`).
+//
+
+use versioning::versioning;
+
+#[versioning(v1_0_0, v2_0_0, deny_warnings = true)]
+pub enum Message {
+    #[version("v2_0_0")]
+    Ping,
+    #[version("v2_0_0")]
+    Pong,
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+//  UNKNOWN NESTED.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:46:00
+//  Last edited:
+//    14 Oct 2026, 10:46:00
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Checks that unknown versions nested in groups point at the innermost
+//!   group containing them.
+//
+
+use versioning::versioning;
+
+#[versioning(v1_0_0, v2_0_0)]
+pub struct Config {
+    #[version(any("v1_0_0", all(min("v1_0_0"), "v9_0_0")))]
+    pub name: String,
+}
+
+fn main() {}
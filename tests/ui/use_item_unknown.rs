@@ -0,0 +1,24 @@
+//  USE ITEM UNKNOWN.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:29:52
+//  Last edited:
+//    14 Oct 2026, 10:30:02
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Checks that names in a `#[version_use_item(...)]` that the `use`
+//!   doesn't import (e.g., because of a typo) are reported.
+//
+
+use versioning::versioning;
+
+#[versioning(v1_0_0, v2_0_0)]
+mod defs {
+    #[version_use_item("v2_0_0", Mutx)]
+    pub use std::sync::{Arc, Mutex};
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+//  VARIANT FIELD SHIFT.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:46:04
+//  Last edited:
+//    14 Oct 2026, 10:46:04
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Checks that filtering out a non-trailing field of a tuple variant is
+//!   reported (as an error, because of `deny_warnings`).
+//
+
+use versioning::versioning;
+
+#[versioning(v1_0_0, v2_0_0, deny_warnings = true)]
+pub enum Message {
+    Data(#[version("v2_0_0")] String, u64),
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+//  VERSION WITHOUT VERSIONING.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:45:55
+//  Last edited:
+//    14 Oct 2026, 10:45:55
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Checks that `#[version(...)]` reports the missing `#[versioning(...)]`
+//!   when used on its own.
+//
+
+use versioning::version;
+
+#[version("v1_0_0")]
+pub struct Config {
+    pub name: String,
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+//  WINDOW ZERO.rs
+//    by Lut99
+//
+//  Created:
+//    14 Oct 2026, 10:34:59
+//  Last edited:
+//    14 Oct 2026, 10:34:59
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Checks that empty windows are rejected.
+//
+
+use versioning::versioning;
+
+#[versioning(v1_0_0, v2_0_0)]
+pub struct Config {
+    pub name: String,
+    #[version(window("v1_0_0", 0))]
+    pub port: u16,
+}
+
+fn main() {}